@@ -0,0 +1,177 @@
+use reqwest::{
+    multipart::{Form, Part},
+    Body,
+};
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+
+use super::Result;
+
+/// File to upload along with a request, such as an image attached to a message.
+///
+/// Discord docs: https://discord.com/developers/docs/reference#uploading-files
+pub struct AttachmentFile {
+    pub filename: String,
+    pub content: AttachmentContent,
+    /// Alt text, for files attached to messages.
+    pub description: Option<String>,
+}
+
+pub enum AttachmentContent {
+    Bytes(Vec<u8>),
+    /// Streamed rather than read into memory first, such as a `tokio::fs::File`.
+    /// Requests with streamed files aren't retried when rate limited.
+    Stream(Body),
+}
+
+impl AttachmentFile {
+    pub fn from_bytes(filename: impl Into<String>, bytes: impl Into<Vec<u8>>) -> Self {
+        AttachmentFile {
+            filename: filename.into(),
+            content: AttachmentContent::Bytes(bytes.into()),
+            description: None,
+        }
+    }
+
+    pub fn from_stream(filename: impl Into<String>, stream: impl Into<Body>) -> Self {
+        AttachmentFile {
+            filename: filename.into(),
+            content: AttachmentContent::Stream(stream.into()),
+            description: None,
+        }
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub(super) fn into_part(self) -> Part {
+        let part = match self.content {
+            AttachmentContent::Bytes(bytes) => Part::bytes(bytes),
+            AttachmentContent::Stream(body) => Part::stream(body),
+        };
+        part.file_name(self.filename)
+    }
+}
+
+/// Encodes the body as `payload_json` with each file as `files[n]`.
+///
+/// `attachments_in` is the key of the object in the body holding `attachments`
+/// (eg, `data` for interaction responses), or `None` if it's the body itself.
+pub(super) fn multipart_form<B: Serialize + ?Sized>(
+    body: &B,
+    attachments_in: Option<&str>,
+    files: Vec<AttachmentFile>,
+) -> Result<Form> {
+    let mut form = Form::new().text("payload_json", payload_json(body, attachments_in, &files)?);
+    for (id, file) in files.into_iter().enumerate() {
+        form = form.part(format!("files[{id}]"), file.into_part());
+    }
+
+    Ok(form)
+}
+
+/// A partial attachment object with an `id` of `n` is appended to the body's `attachments`
+/// for each file, after any attachments already in the body (such as existing attachments to keep
+/// when editing a message).
+fn payload_json<B: Serialize + ?Sized>(
+    body: &B,
+    attachments_in: Option<&str>,
+    files: &[AttachmentFile],
+) -> Result<String> {
+    let mut payload = serde_json::to_value(body)?;
+    let parent = match attachments_in {
+        // Responses without any data (eg, `Pong`) are given an object to put attachments in.
+        Some(key) => payload.as_object_mut().map(|payload| {
+            let parent = payload.entry(key).or_insert(Value::Null);
+            if parent.is_null() {
+                *parent = Value::Object(Map::new());
+            }
+            parent
+        }),
+        None => Some(&mut payload),
+    };
+    if let Some(Value::Object(parent)) = parent {
+        let attachments = parent
+            .entry("attachments")
+            .or_insert_with(|| Value::Array(vec![]));
+        if !attachments.is_array() {
+            *attachments = Value::Array(vec![]);
+        }
+        let attachments = attachments
+            .as_array_mut()
+            .expect("Replaced with an array if it wasn't one");
+
+        for (id, file) in files.iter().enumerate() {
+            let mut attachment = json!({
+                "id": id,
+                "filename": file.filename,
+            });
+            if let Some(description) = &file.description {
+                attachment["description"] = Value::from(description.as_str());
+            }
+            attachments.push(attachment);
+        }
+    }
+
+    Ok(serde_json::to_string(&payload)?)
+}
+
+#[test]
+fn test_payload_json() {
+    let body = json!({
+        "content": "Report attached",
+        "attachments": [{"id": "1234"}],
+    });
+    let files = vec![
+        AttachmentFile::from_bytes("report.png", b"png".to_vec()).description("Screenshot"),
+        AttachmentFile::from_bytes("log.txt", "log"),
+    ];
+
+    assert_eq!(
+        json!({
+            "content": "Report attached",
+            "attachments": [
+                {"id": "1234"},
+                {"id": 0, "filename": "report.png", "description": "Screenshot"},
+                {"id": 1, "filename": "log.txt"},
+            ],
+        }),
+        serde_json::from_str::<Value>(&payload_json(&body, None, &files).unwrap()).unwrap()
+    );
+}
+
+#[test]
+fn test_payload_json_nested() {
+    let body = json!({
+        "type": 4,
+        "data": {"content": "Report attached"},
+    });
+    let files = vec![AttachmentFile::from_bytes("report.png", b"png".to_vec())];
+
+    assert_eq!(
+        json!({
+            "type": 4,
+            "data": {
+                "content": "Report attached",
+                "attachments": [{"id": 0, "filename": "report.png"}],
+            },
+        }),
+        serde_json::from_str::<Value>(&payload_json(&body, Some("data"), &files).unwrap()).unwrap()
+    );
+}
+
+#[test]
+fn test_payload_json_nested_without_data() {
+    let body = json!({"type": 6});
+    let files = vec![AttachmentFile::from_bytes("report.png", b"png".to_vec())];
+
+    assert_eq!(
+        json!({
+            "type": 6,
+            "data": {"attachments": [{"id": 0, "filename": "report.png"}]},
+        }),
+        serde_json::from_str::<Value>(&payload_json(&body, Some("data"), &files).unwrap()).unwrap()
+    );
+}
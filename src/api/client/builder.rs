@@ -0,0 +1,239 @@
+use std::{fmt, sync::Arc, time::Duration};
+
+use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION, USER_AGENT};
+use url::Url;
+
+use crate::api::objects::webhook::WebhookId;
+
+use super::{
+    http_client::HttpClient,
+    ratelimit::{InMemoryRatelimiter, Ratelimiter},
+    Api, Result, WebhookClient,
+};
+
+/// Discord docs: https://discord.com/developers/docs/reference#authentication
+#[cfg_attr(feature = "clone", derive(Clone))]
+pub enum Token {
+    Bot(String),
+    /// OAuth2 access token.
+    Bearer(String),
+}
+
+impl Token {
+    fn header_value(&self) -> Result<HeaderValue> {
+        let mut value = HeaderValue::try_from(match self {
+            Token::Bot(token) => format!("Bot {token}"),
+            Token::Bearer(token) => format!("Bearer {token}"),
+        })?;
+        value.set_sensitive(true);
+        Ok(value)
+    }
+}
+
+// Tokens are left out so they don't end up in logs.
+#[cfg(feature = "debug")]
+impl fmt::Debug for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Token::Bot(_) => f.write_str("Bot(..)"),
+            Token::Bearer(_) => f.write_str("Bearer(..)"),
+        }
+    }
+}
+
+/// Discord docs: https://discord.com/developers/docs/reference#api-versioning
+#[derive(Copy, Clone, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub enum ApiVersion {
+    V9,
+    #[default]
+    V10,
+}
+
+impl fmt::Display for ApiVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ApiVersion::V9 => f.write_str("9"),
+            ApiVersion::V10 => f.write_str("10"),
+        }
+    }
+}
+
+/// Builds an `Api`, defaulting to v10 of the Discord API with no authentication.
+///
+/// ```rust
+/// use datrope::api::client::{ApiBuilder, Token};
+///
+/// let api = ApiBuilder::new()
+///     .token(Token::Bot(String::from("token")))
+///     .build()
+///     .unwrap();
+/// ```
+#[cfg_attr(feature = "clone", derive(Clone))]
+pub struct ApiBuilder {
+    token: Option<Token>,
+    base_url: Url,
+    version: ApiVersion,
+    user_agent: String,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    ratelimiter: Arc<dyn Ratelimiter>,
+    max_retries: u32,
+}
+
+#[cfg(feature = "debug")]
+impl fmt::Debug for ApiBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ApiBuilder")
+            .field("token", &self.token)
+            .field("base_url", &self.base_url)
+            .field("version", &self.version)
+            .field("user_agent", &self.user_agent)
+            .field("timeout", &self.timeout)
+            .field("connect_timeout", &self.connect_timeout)
+            .field("max_retries", &self.max_retries)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for ApiBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ApiBuilder {
+    pub const BASE_URL: &'static str = "https://discord.com/api/";
+
+    pub fn new() -> Self {
+        ApiBuilder {
+            token: None,
+            base_url: Url::parse(Self::BASE_URL).expect("Base URL should be valid"),
+            version: ApiVersion::default(),
+            user_agent: user_agent(env!("CARGO_PKG_REPOSITORY"), env!("CARGO_PKG_VERSION")),
+            timeout: None,
+            connect_timeout: None,
+            ratelimiter: Arc::new(InMemoryRatelimiter::default()),
+            max_retries: 3,
+        }
+    }
+
+    /// Sent as the `Authorization` header with every request.
+    pub fn token(mut self, token: Token) -> Self {
+        self.token = Some(token);
+        self
+    }
+
+    /// Base URL of the API, without the version (eg, `https://discord.com/api/`).
+    pub fn base_url(mut self, base_url: Url) -> Self {
+        self.base_url = base_url;
+        self
+    }
+
+    pub fn version(mut self, version: ApiVersion) -> Self {
+        self.version = version;
+        self
+    }
+
+    /// Replaces the library's URL and version in the `User-Agent` header
+    /// with those of the bot, keeping the `DiscordBot (url, version)` format Discord requires.
+    ///
+    /// Discord docs: https://discord.com/developers/docs/reference#user-agent
+    pub fn user_agent(mut self, url: &str, version: &str) -> Self {
+        self.user_agent = user_agent(url, version);
+        self
+    }
+
+    /// Timeout for each request, from connecting until the response body has been read.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn connect_timeout(mut self, connect_timeout: Duration) -> Self {
+        self.connect_timeout = Some(connect_timeout);
+        self
+    }
+
+    /// Replaces the default in-memory rate limiter, such as with one shared between processes.
+    pub fn ratelimiter(mut self, ratelimiter: impl Ratelimiter + 'static) -> Self {
+        self.ratelimiter = Arc::new(ratelimiter);
+        self
+    }
+
+    /// Times to retry a request that was rate limited anyway. Defaults to 3.
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    pub fn build(self) -> Result<Api> {
+        Ok(Api::from_http_client(self.http_client()?))
+    }
+
+    /// Builds a client for a single webhook, authenticated by its token.
+    pub fn build_webhook_client(
+        self,
+        webhook_id: WebhookId,
+        webhook_token: impl Into<String>,
+    ) -> Result<WebhookClient> {
+        Ok(WebhookClient::new(
+            self.http_client()?,
+            webhook_id,
+            webhook_token.into(),
+        ))
+    }
+
+    fn http_client(self) -> Result<HttpClient> {
+        let mut headers = HeaderMap::new();
+        headers.insert(USER_AGENT, HeaderValue::try_from(&self.user_agent)?);
+        if let Some(token) = &self.token {
+            headers.insert(AUTHORIZATION, token.header_value()?);
+        }
+
+        let mut client = reqwest::Client::builder().default_headers(headers);
+        if let Some(timeout) = self.timeout {
+            client = client.timeout(timeout);
+        }
+        if let Some(connect_timeout) = self.connect_timeout {
+            client = client.connect_timeout(connect_timeout);
+        }
+
+        Ok(HttpClient::new(
+            self.versioned_base_url()?,
+            client.build()?,
+            self.ratelimiter,
+            self.max_retries,
+        ))
+    }
+
+    fn versioned_base_url(&self) -> Result<Url> {
+        let mut base_url = self.base_url.clone();
+        if !base_url.path().ends_with('/') {
+            base_url.set_path(&format!("{}/", base_url.path()));
+        }
+
+        Ok(base_url.join(&format!("v{}/", self.version))?)
+    }
+}
+
+fn user_agent(url: &str, version: &str) -> String {
+    format!("DiscordBot ({url}, {version})")
+}
+
+#[test]
+fn test_versioned_base_url() {
+    let builder = ApiBuilder::new();
+    assert_eq!(
+        "https://discord.com/api/v10/",
+        builder.versioned_base_url().unwrap().as_str()
+    );
+
+    let builder = ApiBuilder::new()
+        .base_url(Url::parse("http://localhost:8080/api").unwrap())
+        .version(ApiVersion::V9);
+    assert_eq!(
+        "http://localhost:8080/api/v9/",
+        builder.versioned_base_url().unwrap().as_str()
+    );
+}
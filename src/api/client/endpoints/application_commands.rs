@@ -0,0 +1,436 @@
+use std::sync::Arc;
+
+use serde::Serialize;
+
+use crate::api::{
+    client::{http_client::HttpClient, Result},
+    objects::{
+        application::ApplicationId,
+        application_command::{
+            ApplicationCommand, ApplicationCommandId, ApplicationCommandPermission,
+            GuildApplicationCommandPermissions,
+        },
+        guild::GuildId,
+    },
+};
+
+#[derive(Clone)]
+pub struct ApplicationCommands {
+    http_client: Arc<HttpClient>,
+}
+
+impl ApplicationCommands {
+    pub fn new(http_client: Arc<HttpClient>) -> Self {
+        ApplicationCommands { http_client }
+    }
+
+    /// Includes every translation instead of only the user's locale when `with_localizations`
+    /// is `true`.
+    ///
+    /// https://discord.com/developers/docs/interactions/application-commands#get-global-application-commands
+    pub async fn get_global_application_commands(
+        &self,
+        application_id: &ApplicationId,
+        with_localizations: bool,
+    ) -> Result<Vec<ApplicationCommand>> {
+        #[derive(Serialize)]
+        struct Query {
+            with_localizations: bool,
+        }
+
+        self.http_client
+            .get(&format!("/applications/{}/commands", application_id.0))?
+            .query(&Query { with_localizations })
+            .send_json()
+            .await
+    }
+
+    /// Replaces the existing command with the same name.
+    ///
+    /// https://discord.com/developers/docs/interactions/application-commands#create-global-application-command
+    pub async fn create_global_application_command(
+        &self,
+        application_id: &ApplicationId,
+        command: &requests::CreateApplicationCommand,
+    ) -> Result<ApplicationCommand> {
+        self.http_client
+            .post(&format!("/applications/{}/commands", application_id.0))?
+            .json(command)
+            .send_json()
+            .await
+    }
+
+    /// https://discord.com/developers/docs/interactions/application-commands#get-global-application-command
+    pub async fn get_global_application_command(
+        &self,
+        application_id: &ApplicationId,
+        command_id: &ApplicationCommandId,
+    ) -> Result<ApplicationCommand> {
+        self.http_client
+            .get(&format!(
+                "/applications/{}/commands/{}",
+                application_id.0, command_id.0
+            ))?
+            .send_json()
+            .await
+    }
+
+    /// https://discord.com/developers/docs/interactions/application-commands#edit-global-application-command
+    pub async fn edit_global_application_command(
+        &self,
+        application_id: &ApplicationId,
+        command_id: &ApplicationCommandId,
+        command: &requests::EditApplicationCommand,
+    ) -> Result<ApplicationCommand> {
+        self.http_client
+            .patch(&format!(
+                "/applications/{}/commands/{}",
+                application_id.0, command_id.0
+            ))?
+            .json(command)
+            .send_json()
+            .await
+    }
+
+    /// https://discord.com/developers/docs/interactions/application-commands#delete-global-application-command
+    pub async fn delete_global_application_command(
+        &self,
+        application_id: &ApplicationId,
+        command_id: &ApplicationCommandId,
+    ) -> Result<()> {
+        self.http_client
+            .delete(&format!(
+                "/applications/{}/commands/{}",
+                application_id.0, command_id.0
+            ))?
+            .send_empty()
+            .await
+    }
+
+    /// Replaces all global commands, deleting any that aren't in `commands`.
+    ///
+    /// https://discord.com/developers/docs/interactions/application-commands#bulk-overwrite-global-application-commands
+    pub async fn bulk_overwrite_global_application_commands(
+        &self,
+        application_id: &ApplicationId,
+        commands: &[requests::CreateApplicationCommand],
+    ) -> Result<Vec<ApplicationCommand>> {
+        self.http_client
+            .put(&format!("/applications/{}/commands", application_id.0))?
+            .json(commands)
+            .send_json()
+            .await
+    }
+
+    /// Includes every translation instead of only the user's locale when `with_localizations`
+    /// is `true`.
+    ///
+    /// https://discord.com/developers/docs/interactions/application-commands#get-guild-application-commands
+    pub async fn get_guild_application_commands(
+        &self,
+        application_id: &ApplicationId,
+        guild_id: &GuildId,
+        with_localizations: bool,
+    ) -> Result<Vec<ApplicationCommand>> {
+        #[derive(Serialize)]
+        struct Query {
+            with_localizations: bool,
+        }
+
+        self.http_client
+            .get(&format!(
+                "/applications/{}/guilds/{}/commands",
+                application_id.0, guild_id.0
+            ))?
+            .query(&Query { with_localizations })
+            .send_json()
+            .await
+    }
+
+    /// Replaces the existing command with the same name.
+    ///
+    /// https://discord.com/developers/docs/interactions/application-commands#create-guild-application-command
+    pub async fn create_guild_application_command(
+        &self,
+        application_id: &ApplicationId,
+        guild_id: &GuildId,
+        command: &requests::CreateApplicationCommand,
+    ) -> Result<ApplicationCommand> {
+        self.http_client
+            .post(&format!(
+                "/applications/{}/guilds/{}/commands",
+                application_id.0, guild_id.0
+            ))?
+            .json(command)
+            .send_json()
+            .await
+    }
+
+    /// https://discord.com/developers/docs/interactions/application-commands#get-guild-application-command
+    pub async fn get_guild_application_command(
+        &self,
+        application_id: &ApplicationId,
+        guild_id: &GuildId,
+        command_id: &ApplicationCommandId,
+    ) -> Result<ApplicationCommand> {
+        self.http_client
+            .get(&format!(
+                "/applications/{}/guilds/{}/commands/{}",
+                application_id.0, guild_id.0, command_id.0
+            ))?
+            .send_json()
+            .await
+    }
+
+    /// https://discord.com/developers/docs/interactions/application-commands#edit-guild-application-command
+    pub async fn edit_guild_application_command(
+        &self,
+        application_id: &ApplicationId,
+        guild_id: &GuildId,
+        command_id: &ApplicationCommandId,
+        command: &requests::EditApplicationCommand,
+    ) -> Result<ApplicationCommand> {
+        self.http_client
+            .patch(&format!(
+                "/applications/{}/guilds/{}/commands/{}",
+                application_id.0, guild_id.0, command_id.0
+            ))?
+            .json(command)
+            .send_json()
+            .await
+    }
+
+    /// https://discord.com/developers/docs/interactions/application-commands#delete-guild-application-command
+    pub async fn delete_guild_application_command(
+        &self,
+        application_id: &ApplicationId,
+        guild_id: &GuildId,
+        command_id: &ApplicationCommandId,
+    ) -> Result<()> {
+        self.http_client
+            .delete(&format!(
+                "/applications/{}/guilds/{}/commands/{}",
+                application_id.0, guild_id.0, command_id.0
+            ))?
+            .send_empty()
+            .await
+    }
+
+    /// Replaces all of the guild's commands, deleting any that aren't in `commands`.
+    ///
+    /// https://discord.com/developers/docs/interactions/application-commands#bulk-overwrite-guild-application-commands
+    pub async fn bulk_overwrite_guild_application_commands(
+        &self,
+        application_id: &ApplicationId,
+        guild_id: &GuildId,
+        commands: &[requests::CreateApplicationCommand],
+    ) -> Result<Vec<ApplicationCommand>> {
+        self.http_client
+            .put(&format!(
+                "/applications/{}/guilds/{}/commands",
+                application_id.0, guild_id.0
+            ))?
+            .json(commands)
+            .send_json()
+            .await
+    }
+
+    /// Permissions for every command in the guild that has any.
+    ///
+    /// https://discord.com/developers/docs/interactions/application-commands#get-guild-application-command-permissions
+    pub async fn get_guild_application_command_permissions(
+        &self,
+        application_id: &ApplicationId,
+        guild_id: &GuildId,
+    ) -> Result<Vec<GuildApplicationCommandPermissions>> {
+        self.http_client
+            .get(&format!(
+                "/applications/{}/guilds/{}/commands/permissions",
+                application_id.0, guild_id.0
+            ))?
+            .send_json()
+            .await
+    }
+
+    /// https://discord.com/developers/docs/interactions/application-commands#get-application-command-permissions
+    pub async fn get_application_command_permissions(
+        &self,
+        application_id: &ApplicationId,
+        guild_id: &GuildId,
+        command_id: &ApplicationCommandId,
+    ) -> Result<GuildApplicationCommandPermissions> {
+        self.http_client
+            .get(&format!(
+                "/applications/{}/guilds/{}/commands/{}/permissions",
+                application_id.0, guild_id.0, command_id.0
+            ))?
+            .send_json()
+            .await
+    }
+
+    /// Replaces the command's permissions, up to 100. Bot tokens can't do this, so the `Api`
+    /// needs a `Token::Bearer` with the `applications.commands.permissions.update` scope.
+    ///
+    /// https://discord.com/developers/docs/interactions/application-commands#edit-application-command-permissions
+    pub async fn edit_application_command_permissions(
+        &self,
+        application_id: &ApplicationId,
+        guild_id: &GuildId,
+        command_id: &ApplicationCommandId,
+        permissions: &[ApplicationCommandPermission],
+    ) -> Result<GuildApplicationCommandPermissions> {
+        #[derive(Serialize)]
+        struct Body<'a> {
+            permissions: &'a [ApplicationCommandPermission],
+        }
+
+        self.http_client
+            .put(&format!(
+                "/applications/{}/guilds/{}/commands/{}/permissions",
+                application_id.0, guild_id.0, command_id.0
+            ))?
+            .json(&Body { permissions })
+            .send_json()
+            .await
+    }
+}
+
+pub mod requests {
+    use serde::Serialize;
+
+    use crate::api::objects::{
+        application::IntegrationType,
+        application_command::{
+            ApplicationCommandOption, ApplicationCommandType, Description, Name,
+        },
+        interactions::InteractionContextType,
+        permissions::Permissions,
+        Translation,
+    };
+
+    /// Also used for guild commands, which ignore `integration_types` and `contexts`.
+    ///
+    /// Discord docs: https://discord.com/developers/docs/interactions/application-commands#create-global-application-command-json-params
+    #[cfg_attr(feature = "clone", derive(Clone))]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    #[derive(Serialize)]
+    pub struct CreateApplicationCommand {
+        /// 1-32 characters, lowercase for `ChatInput` commands.
+        pub name: Name,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub name_localizations: Option<Translation<Name>>,
+        /// 1-100 characters, only for (and required by) `ChatInput` commands.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub description: Option<Description>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub description_localizations: Option<Translation<Description>>,
+        /// Only for `ChatInput` commands, up to 25.
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        pub options: Vec<ApplicationCommandOption>,
+        /// Permissions members need to use the command by default.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub default_member_permissions: Option<Permissions>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub integration_types: Option<Vec<IntegrationType>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub contexts: Option<Vec<InteractionContextType>>,
+        /// Defaults to `ChatInput`.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub r#type: Option<ApplicationCommandType>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub nsfw: Option<bool>,
+    }
+
+    impl CreateApplicationCommand {
+        /// Command with the given name, and everything else left unset.
+        pub fn new(name: Name) -> Self {
+            CreateApplicationCommand {
+                name,
+                name_localizations: None,
+                description: None,
+                description_localizations: None,
+                options: vec![],
+                default_member_permissions: None,
+                integration_types: None,
+                contexts: None,
+                r#type: None,
+                nsfw: None,
+            }
+        }
+    }
+
+    /// Fields set to `None` are left as is, and fields with an `Option<Option<_>>` are cleared
+    /// when set to `Some(None)`. Guild commands ignore `integration_types` and `contexts`.
+    ///
+    /// Discord docs: https://discord.com/developers/docs/interactions/application-commands#edit-global-application-command-json-params
+    #[derive(Default)]
+    #[cfg_attr(feature = "clone", derive(Clone))]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    #[derive(Serialize)]
+    pub struct EditApplicationCommand {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub name: Option<Name>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub name_localizations: Option<Option<Translation<Name>>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub description: Option<Description>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub description_localizations: Option<Option<Translation<Description>>>,
+        /// Replaces all of the command's options.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub options: Option<Vec<ApplicationCommandOption>>,
+        /// `Some(None)` lets everyone use the command by default.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub default_member_permissions: Option<Option<Permissions>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub integration_types: Option<Vec<IntegrationType>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub contexts: Option<Vec<InteractionContextType>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub nsfw: Option<bool>,
+    }
+}
+
+#[test]
+fn test_create_application_command() {
+    use crate::api::objects::{
+        application_command::{
+            ApplicationCommandOption, ApplicationCommandOptionChoice, ApplicationCommandOptionType,
+            ChoiceName, ChoiceValue, Description, Name, OptionDescription, OptionName,
+        },
+        interactions::InteractionContextType,
+        Translation,
+    };
+
+    let command = requests::CreateApplicationCommand {
+        description: Some(Description(String::from("Rolls a die"))),
+        options: vec![ApplicationCommandOption {
+            required: Some(true),
+            choices: vec![ApplicationCommandOptionChoice {
+                name: ChoiceName(String::from("d6")),
+                name_localizations: None,
+                value: ChoiceValue::Integer(6),
+            }],
+            ..ApplicationCommandOption::new(
+                ApplicationCommandOptionType::Integer,
+                OptionName(String::from("sides")),
+                OptionDescription(String::from("Number of sides")),
+            )
+        }],
+        name_localizations: Some(Translation {
+            french: Some(Name(String::from("lancer"))),
+            ..Default::default()
+        }),
+        contexts: Some(vec![InteractionContextType::Guild]),
+        ..requests::CreateApplicationCommand::new(Name(String::from("roll")))
+    };
+
+    assert_eq!(
+        concat!(
+            r#"{"name":"roll","name_localizations":{"fr":"lancer"},"description":"Rolls a die","#,
+            r#""options":[{"type":4,"name":"sides","description":"Number of sides","required":true,"#,
+            r#""choices":[{"name":"d6","value":6}]}],"contexts":[0]}"#
+        ),
+        serde_json::to_string(&command).unwrap()
+    );
+}
@@ -0,0 +1,190 @@
+use std::sync::Arc;
+
+use futures_util::{stream, Stream};
+
+use crate::api::{
+    client::{http_client::HttpClient, Result},
+    objects::{
+        audit_log::{AuditLog, AuditLogEntryId},
+        guild::GuildId,
+    },
+};
+
+#[derive(Clone)]
+pub struct AuditLogs {
+    http_client: Arc<HttpClient>,
+}
+
+impl AuditLogs {
+    pub fn new(http_client: Arc<HttpClient>) -> Self {
+        AuditLogs { http_client }
+    }
+
+    /// Entries are newest first, unless only `after` is set.
+    ///
+    /// https://discord.com/developers/docs/resources/audit-log#get-guild-audit-log
+    pub async fn get_guild_audit_log(
+        &self,
+        guild_id: &GuildId,
+        query: &requests::GetGuildAuditLog,
+    ) -> Result<AuditLog> {
+        self.http_client
+            .get(&format!("/guilds/{}/audit-logs", guild_id.0))?
+            .query(query)
+            .send_json()
+            .await
+    }
+
+    /// Pages backwards through the audit log, starting from `query.before` (or the newest entry)
+    /// and ending at `query.after` (or the oldest entry). When only `after` is set,
+    /// pages forwards from it to the newest entry instead. Stops after the first error.
+    pub fn get_guild_audit_log_pages(
+        &self,
+        guild_id: GuildId,
+        query: requests::GetGuildAuditLog,
+    ) -> impl Stream<Item = Result<AuditLog>> + Send + 'static {
+        let audit_logs = self.clone();
+        stream::try_unfold(Some(query), move |query| {
+            let audit_logs = audit_logs.clone();
+            let guild_id = GuildId(guild_id.0.clone());
+            async move {
+                let Some(query) = query else {
+                    return Ok(None);
+                };
+
+                let page = audit_logs.get_guild_audit_log(&guild_id, &query).await?;
+                let next_query = next_page_query(query, &page);
+
+                Ok(Some((page, next_query)))
+            }
+        })
+    }
+}
+
+/// Query for the page after `page`, or `None` if `page` was the last one.
+fn next_page_query(
+    mut query: requests::GetGuildAuditLog,
+    page: &AuditLog,
+) -> Option<requests::GetGuildAuditLog> {
+    let limit = usize::from(
+        query
+            .limit
+            .unwrap_or(requests::GetGuildAuditLog::DEFAULT_LIMIT),
+    );
+    if page.audit_log_entries.len() < limit {
+        return None;
+    }
+
+    let ids = page
+        .audit_log_entries
+        .iter()
+        .filter_map(|entry| entry.id.0.parse::<u64>().ok());
+    // With only `after` set, Discord returns the entries just after it, so page forwards.
+    if query.after.is_some() && query.before.is_none() {
+        query.after = Some(AuditLogEntryId(ids.max()?.to_string()));
+    } else {
+        query.before = Some(AuditLogEntryId(ids.min()?.to_string()));
+    }
+
+    Some(query)
+}
+
+pub mod requests {
+    use serde::Serialize;
+
+    use crate::api::objects::{
+        audit_log::{AuditLogEntryId, AuditLogEvent},
+        user::UserId,
+    };
+
+    /// Discord docs: https://discord.com/developers/docs/resources/audit-log#get-guild-audit-log-query-string-params
+    #[derive(Default)]
+    #[cfg_attr(feature = "clone", derive(Clone))]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    #[derive(Serialize)]
+    pub struct GetGuildAuditLog {
+        /// Entries for actions by the user.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub user_id: Option<UserId>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub action_type: Option<AuditLogEvent>,
+        /// Entries older than the entry.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub before: Option<AuditLogEntryId>,
+        /// Entries newer than the entry.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub after: Option<AuditLogEntryId>,
+        /// 1-100, defaults to 50.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub limit: Option<u8>,
+    }
+
+    impl GetGuildAuditLog {
+        pub const DEFAULT_LIMIT: u8 = 50;
+    }
+}
+
+#[test]
+fn test_next_page_query() {
+    use crate::api::objects::audit_log::{AuditLogEntry, AuditLogEvent};
+
+    let page = |ids: &[&str]| AuditLog {
+        application_commands: vec![],
+        audit_log_entries: ids
+            .iter()
+            .map(|id| AuditLogEntry {
+                target_id: None,
+                changes: vec![],
+                user_id: None,
+                id: AuditLogEntryId(id.to_string()),
+                action_type: AuditLogEvent::MemberBanAdd,
+                options: None,
+                reason: None,
+            })
+            .collect(),
+        auto_moderation_rules: vec![],
+        guild_scheduled_events: vec![],
+        integrations: vec![],
+        threads: vec![],
+        users: vec![],
+        webhooks: vec![],
+    };
+    let query = |before: Option<&str>, after: Option<&str>| requests::GetGuildAuditLog {
+        before: before.map(|id| AuditLogEntryId(id.to_string())),
+        after: after.map(|id| AuditLogEntryId(id.to_string())),
+        limit: Some(3),
+        ..Default::default()
+    };
+    let cursors = |query: Option<requests::GetGuildAuditLog>| {
+        query.map(|query| (query.before.map(|id| id.0), query.after.map(|id| id.0)))
+    };
+
+    // Backwards from the newest entry, by the smallest rather than the last ID.
+    assert_eq!(
+        Some((Some(String::from("98")), None)),
+        cursors(next_page_query(
+            query(None, None),
+            &page(&["100", "98", "99"])
+        ))
+    );
+    assert_eq!(
+        Some((Some(String::from("8")), Some(String::from("5")))),
+        cursors(next_page_query(
+            query(Some("11"), Some("5")),
+            &page(&["10", "9", "8"])
+        ))
+    );
+    // Forwards from `after` when only it is set.
+    assert_eq!(
+        Some((None, Some(String::from("8")))),
+        cursors(next_page_query(
+            query(None, Some("5")),
+            &page(&["6", "7", "8"])
+        ))
+    );
+    // A page shorter than the limit is the last one.
+    assert_eq!(
+        None,
+        cursors(next_page_query(query(None, Some("5")), &page(&["6", "7"])))
+    );
+}
@@ -0,0 +1,372 @@
+use std::sync::Arc;
+
+use crate::api::{
+    client::{http_client::HttpClient, Result},
+    objects::{
+        auto_moderation::{AutoModerationRule, AutoModerationRuleId},
+        guild::GuildId,
+    },
+};
+
+#[derive(Clone)]
+pub struct AutoModeration {
+    http_client: Arc<HttpClient>,
+}
+
+impl AutoModeration {
+    pub fn new(http_client: Arc<HttpClient>) -> Self {
+        AutoModeration { http_client }
+    }
+
+    /// https://discord.com/developers/docs/resources/auto-moderation#list-auto-moderation-rules-for-guild
+    pub async fn list_auto_moderation_rules_for_guild(
+        &self,
+        guild_id: &GuildId,
+    ) -> Result<Vec<AutoModerationRule>> {
+        self.http_client
+            .get(&format!("/guilds/{}/auto-moderation/rules", guild_id.0))?
+            .send_json()
+            .await
+    }
+
+    /// https://discord.com/developers/docs/resources/auto-moderation#get-auto-moderation-rule
+    pub async fn get_auto_moderation_rule(
+        &self,
+        guild_id: &GuildId,
+        rule_id: &AutoModerationRuleId,
+    ) -> Result<AutoModerationRule> {
+        self.http_client
+            .get(&format!(
+                "/guilds/{}/auto-moderation/rules/{}",
+                guild_id.0, rule_id.0
+            ))?
+            .send_json()
+            .await
+    }
+
+    /// Returns `ApiError::InvalidTriggerMetadata` without sending the request
+    /// if `trigger_metadata` is missing or doesn't fit the rule's trigger type.
+    ///
+    /// https://discord.com/developers/docs/resources/auto-moderation#create-auto-moderation-rule
+    pub async fn create_auto_moderation_rule(
+        &self,
+        guild_id: &GuildId,
+        rule: &requests::CreateAutoModerationRule,
+        reason: Option<&str>,
+    ) -> Result<AutoModerationRule> {
+        rule.validate()?;
+
+        self.http_client
+            .post(&format!("/guilds/{}/auto-moderation/rules", guild_id.0))?
+            .json(rule)
+            .audit_log_reason(reason)
+            .send_json()
+            .await
+    }
+
+    /// https://discord.com/developers/docs/resources/auto-moderation#modify-auto-moderation-rule
+    pub async fn modify_auto_moderation_rule(
+        &self,
+        guild_id: &GuildId,
+        rule_id: &AutoModerationRuleId,
+        rule: &requests::ModifyAutoModerationRule,
+        reason: Option<&str>,
+    ) -> Result<AutoModerationRule> {
+        self.http_client
+            .patch(&format!(
+                "/guilds/{}/auto-moderation/rules/{}",
+                guild_id.0, rule_id.0
+            ))?
+            .json(rule)
+            .audit_log_reason(reason)
+            .send_json()
+            .await
+    }
+
+    /// https://discord.com/developers/docs/resources/auto-moderation#delete-auto-moderation-rule
+    pub async fn delete_auto_moderation_rule(
+        &self,
+        guild_id: &GuildId,
+        rule_id: &AutoModerationRuleId,
+        reason: Option<&str>,
+    ) -> Result<()> {
+        self.http_client
+            .delete(&format!(
+                "/guilds/{}/auto-moderation/rules/{}",
+                guild_id.0, rule_id.0
+            ))?
+            .audit_log_reason(reason)
+            .send_empty()
+            .await
+    }
+}
+
+pub mod requests {
+    use serde::Serialize;
+    use thiserror::Error;
+
+    use crate::api::objects::{
+        auto_moderation::{AutoModerationAction, EventType, TriggerMetadata, TriggerType},
+        channel::ChannelId,
+        permissions::RoleId,
+    };
+
+    /// Discord docs: https://discord.com/developers/docs/resources/auto-moderation#create-auto-moderation-rule-json-params
+    #[cfg_attr(feature = "clone", derive(Clone))]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    #[derive(Serialize)]
+    pub struct CreateAutoModerationRule {
+        pub name: String,
+        pub event_type: EventType,
+        pub trigger_type: TriggerType,
+        /// Required for every trigger type except `Spam`.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub trigger_metadata: Option<TriggerMetadata>,
+        pub actions: Vec<AutoModerationAction>,
+        /// Defaults to `false`.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub enabled: Option<bool>,
+        /// Up to 20 roles.
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        pub exempt_roles: Vec<RoleId>,
+        /// Up to 50 channels.
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        pub exempt_channels: Vec<ChannelId>,
+    }
+
+    impl CreateAutoModerationRule {
+        /// Checks that `trigger_metadata` is set if the rule's trigger type needs it,
+        /// and that it's within the limits for the trigger type.
+        pub fn validate(&self) -> Result<(), TriggerMetadataError> {
+            match &self.trigger_metadata {
+                Some(trigger_metadata) => {
+                    validate_trigger_metadata(&self.trigger_type, trigger_metadata)
+                }
+                None if matches!(self.trigger_type, TriggerType::Spam) => Ok(()),
+                None => Err(TriggerMetadataError::Missing),
+            }
+        }
+    }
+
+    /// Fields set to `None` are left as is. The trigger type can't be changed.
+    ///
+    /// Discord docs: https://discord.com/developers/docs/resources/auto-moderation#modify-auto-moderation-rule-json-params
+    #[derive(Default)]
+    #[cfg_attr(feature = "clone", derive(Clone))]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    #[derive(Serialize)]
+    pub struct ModifyAutoModerationRule {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub name: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub event_type: Option<EventType>,
+        /// Can be checked with `validate_trigger_metadata` and the rule's trigger type.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub trigger_metadata: Option<TriggerMetadata>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub actions: Option<Vec<AutoModerationAction>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub enabled: Option<bool>,
+        /// Up to 20 roles.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub exempt_roles: Option<Vec<RoleId>>,
+        /// Up to 50 channels.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub exempt_channels: Option<Vec<ChannelId>>,
+    }
+
+    /// Trigger metadata that Discord would reject for the rule's trigger type.
+    ///
+    /// Discord docs: https://discord.com/developers/docs/resources/auto-moderation#auto-moderation-rule-object-trigger-metadata-field-limits
+    #[derive(Error, Debug)]
+    pub enum TriggerMetadataError {
+        #[error("Trigger metadata is required for every trigger type except `Spam`")]
+        Missing,
+        #[error("`{field}` isn't used by this trigger type")]
+        UnusedField { field: &'static str },
+        #[error("`{field}` has {len} entries, but the limit is {max}")]
+        TooManyEntries {
+            field: &'static str,
+            len: usize,
+            max: usize,
+        },
+        #[error("An entry in `{field}` has {len} characters, but the limit is {max}")]
+        EntryTooLong {
+            field: &'static str,
+            len: usize,
+            max: usize,
+        },
+        #[error("`mention_total_limit` is {0}, but the limit is 50")]
+        MentionLimitTooHigh(u8),
+    }
+
+    /// Checks that `trigger_metadata` only has fields used by `trigger_type`,
+    /// and that they're within Discord's limits.
+    pub fn validate_trigger_metadata(
+        trigger_type: &TriggerType,
+        trigger_metadata: &TriggerMetadata,
+    ) -> Result<(), TriggerMetadataError> {
+        let TriggerMetadata {
+            keyword_filter,
+            regex_patterns,
+            presets,
+            allow_list,
+            mention_total_limit,
+            mention_raid_protection_enabled,
+        } = trigger_metadata;
+
+        let keywords = matches!(
+            trigger_type,
+            TriggerType::Keyword | TriggerType::MemberProfile
+        );
+        let keyword_preset = matches!(trigger_type, TriggerType::KeywordPreset);
+        let mention_spam = matches!(trigger_type, TriggerType::MentionSpam);
+
+        let used = [
+            ("keyword_filter", !keyword_filter.is_empty(), keywords),
+            ("regex_patterns", !regex_patterns.is_empty(), keywords),
+            ("presets", !presets.is_empty(), keyword_preset),
+            (
+                "allow_list",
+                !allow_list.is_empty(),
+                keywords || keyword_preset,
+            ),
+            (
+                "mention_total_limit",
+                mention_total_limit.is_some(),
+                mention_spam,
+            ),
+            (
+                "mention_raid_protection_enabled",
+                mention_raid_protection_enabled.is_some(),
+                mention_spam,
+            ),
+        ];
+        if let Some((field, ..)) = used.iter().find(|(_, set, allowed)| *set && !allowed) {
+            return Err(TriggerMetadataError::UnusedField { field });
+        }
+
+        check_entries("keyword_filter", keyword_filter, 1000, 60)?;
+        check_entries("regex_patterns", regex_patterns, 10, 260)?;
+        check_entries(
+            "allow_list",
+            allow_list,
+            if keyword_preset { 1000 } else { 100 },
+            60,
+        )?;
+        if let Some(limit) = mention_total_limit {
+            if *limit > 50 {
+                return Err(TriggerMetadataError::MentionLimitTooHigh(*limit));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn check_entries(
+        field: &'static str,
+        entries: &[String],
+        max_entries: usize,
+        max_length: usize,
+    ) -> Result<(), TriggerMetadataError> {
+        if entries.len() > max_entries {
+            return Err(TriggerMetadataError::TooManyEntries {
+                field,
+                len: entries.len(),
+                max: max_entries,
+            });
+        }
+        for entry in entries {
+            let len = entry.chars().count();
+            if len > max_length {
+                return Err(TriggerMetadataError::EntryTooLong {
+                    field,
+                    len,
+                    max: max_length,
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[test]
+fn test_validate_trigger_metadata() {
+    use std::assert_matches::assert_matches;
+
+    use crate::api::objects::auto_moderation::{EventType, TriggerMetadata, TriggerType};
+    use requests::{validate_trigger_metadata, TriggerMetadataError};
+
+    let metadata = TriggerMetadata {
+        keyword_filter: vec![String::from("*cat*")],
+        regex_patterns: vec![String::from("c[a@]t")],
+        allow_list: vec![String::from("category")],
+        ..Default::default()
+    };
+    assert_matches!(
+        validate_trigger_metadata(&TriggerType::Keyword, &metadata),
+        Ok(())
+    );
+    assert_matches!(
+        validate_trigger_metadata(&TriggerType::KeywordPreset, &metadata),
+        Err(TriggerMetadataError::UnusedField {
+            field: "keyword_filter"
+        })
+    );
+
+    let metadata = TriggerMetadata {
+        regex_patterns: vec![String::from("a"); 11],
+        ..Default::default()
+    };
+    assert_matches!(
+        validate_trigger_metadata(&TriggerType::MemberProfile, &metadata),
+        Err(TriggerMetadataError::TooManyEntries {
+            field: "regex_patterns",
+            len: 11,
+            max: 10
+        })
+    );
+
+    let metadata = TriggerMetadata {
+        allow_list: vec!["a".repeat(61)],
+        ..Default::default()
+    };
+    assert_matches!(
+        validate_trigger_metadata(&TriggerType::KeywordPreset, &metadata),
+        Err(TriggerMetadataError::EntryTooLong {
+            field: "allow_list",
+            len: 61,
+            max: 60
+        })
+    );
+
+    let metadata = TriggerMetadata {
+        mention_total_limit: Some(51),
+        ..Default::default()
+    };
+    assert_matches!(
+        validate_trigger_metadata(&TriggerType::MentionSpam, &metadata),
+        Err(TriggerMetadataError::MentionLimitTooHigh(51))
+    );
+    assert_matches!(
+        validate_trigger_metadata(&TriggerType::Spam, &metadata),
+        Err(TriggerMetadataError::UnusedField {
+            field: "mention_total_limit"
+        })
+    );
+
+    let mut rule = requests::CreateAutoModerationRule {
+        name: String::from("No mention spam"),
+        event_type: EventType::MessageSend,
+        trigger_type: TriggerType::MentionSpam,
+        trigger_metadata: None,
+        actions: vec![],
+        enabled: None,
+        exempt_roles: vec![],
+        exempt_channels: vec![],
+    };
+    assert_matches!(rule.validate(), Err(TriggerMetadataError::Missing));
+    rule.trigger_type = TriggerType::Spam;
+    assert_matches!(rule.validate(), Ok(()));
+}
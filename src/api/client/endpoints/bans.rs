@@ -0,0 +1,137 @@
+use std::sync::Arc;
+
+use crate::api::{
+    client::{http_client::HttpClient, Result},
+    objects::{
+        guild::{Ban, BulkBan, GuildId},
+        user::UserId,
+    },
+};
+
+#[derive(Clone)]
+pub struct Bans {
+    http_client: Arc<HttpClient>,
+}
+
+impl Bans {
+    pub fn new(http_client: Arc<HttpClient>) -> Self {
+        Bans { http_client }
+    }
+
+    /// Bans ordered by user ID.
+    ///
+    /// https://discord.com/developers/docs/resources/guild#get-guild-bans
+    pub async fn get_guild_bans(
+        &self,
+        guild_id: &GuildId,
+        query: &requests::GetGuildBans,
+    ) -> Result<Vec<Ban>> {
+        self.http_client
+            .get(&format!("/guilds/{}/bans", guild_id.0))?
+            .query(query)
+            .send_json()
+            .await
+    }
+
+    /// https://discord.com/developers/docs/resources/guild#get-guild-ban
+    pub async fn get_guild_ban(&self, guild_id: &GuildId, user_id: &UserId) -> Result<Ban> {
+        self.http_client
+            .get(&format!("/guilds/{}/bans/{}", guild_id.0, user_id.0))?
+            .send_json()
+            .await
+    }
+
+    /// https://discord.com/developers/docs/resources/guild#create-guild-ban
+    pub async fn create_guild_ban(
+        &self,
+        guild_id: &GuildId,
+        user_id: &UserId,
+        ban: &requests::CreateGuildBan,
+        reason: Option<&str>,
+    ) -> Result<()> {
+        self.http_client
+            .put(&format!("/guilds/{}/bans/{}", guild_id.0, user_id.0))?
+            .json(ban)
+            .audit_log_reason(reason)
+            .send_empty()
+            .await
+    }
+
+    /// https://discord.com/developers/docs/resources/guild#remove-guild-ban
+    pub async fn remove_guild_ban(
+        &self,
+        guild_id: &GuildId,
+        user_id: &UserId,
+        reason: Option<&str>,
+    ) -> Result<()> {
+        self.http_client
+            .delete(&format!("/guilds/{}/bans/{}", guild_id.0, user_id.0))?
+            .audit_log_reason(reason)
+            .send_empty()
+            .await
+    }
+
+    /// Bans up to 200 users, erroring if none of them could be banned.
+    ///
+    /// https://discord.com/developers/docs/resources/guild#bulk-guild-ban
+    pub async fn bulk_guild_ban(
+        &self,
+        guild_id: &GuildId,
+        bans: &requests::BulkGuildBan,
+        reason: Option<&str>,
+    ) -> Result<BulkBan> {
+        self.http_client
+            .post(&format!("/guilds/{}/bulk-ban", guild_id.0))?
+            .json(bans)
+            .audit_log_reason(reason)
+            .send_json()
+            .await
+    }
+}
+
+pub mod requests {
+    use serde::Serialize;
+
+    use crate::api::objects::user::UserId;
+
+    /// Discord docs: https://discord.com/developers/docs/resources/guild#get-guild-bans-query-string-params
+    #[derive(Default)]
+    #[cfg_attr(feature = "clone", derive(Clone))]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    #[derive(Serialize)]
+    pub struct GetGuildBans {
+        /// 1-1000, defaults to 1000.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub limit: Option<u16>,
+        /// Returns bans of users with a lower ID.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub before: Option<UserId>,
+        /// Returns bans of users with a greater ID.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub after: Option<UserId>,
+    }
+
+    /// Discord docs: https://discord.com/developers/docs/resources/guild#create-guild-ban-json-params
+    #[derive(Default)]
+    #[cfg_attr(feature = "clone", derive(Clone))]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    #[derive(Serialize)]
+    pub struct CreateGuildBan {
+        /// Seconds of messages to delete, up to 604800 (7 days). Defaults to 0.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub delete_message_seconds: Option<u32>,
+    }
+
+    /// Discord docs: https://discord.com/developers/docs/resources/guild#bulk-guild-ban-json-params
+    #[derive(Default)]
+    #[cfg_attr(feature = "clone", derive(Clone))]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    #[derive(Serialize)]
+    pub struct BulkGuildBan {
+        /// Up to 200 users.
+        pub user_ids: Vec<UserId>,
+        /// Seconds of messages to delete, up to 604800 (7 days). Defaults to 0.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub delete_message_seconds: Option<u32>,
+    }
+}
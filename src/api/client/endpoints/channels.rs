@@ -0,0 +1,398 @@
+use std::sync::Arc;
+
+use serde::Serialize;
+
+use crate::api::{
+    client::{http_client::HttpClient, Result},
+    objects::channel::{Channel, ChannelId, FollowedChannel, OverwriteId},
+};
+
+#[derive(Clone)]
+pub struct Channels {
+    http_client: Arc<HttpClient>,
+}
+
+impl Channels {
+    pub fn new(http_client: Arc<HttpClient>) -> Self {
+        Channels { http_client }
+    }
+
+    /// https://discord.com/developers/docs/resources/channel#get-channel
+    pub async fn get_channel(&self, channel_id: &ChannelId) -> Result<Channel> {
+        self.http_client
+            .get(&format!("/channels/{}", channel_id.0))?
+            .send_json()
+            .await
+    }
+
+    /// https://discord.com/developers/docs/resources/channel#modify-channel
+    pub async fn modify_channel(
+        &self,
+        channel_id: &ChannelId,
+        channel: &requests::ModifyChannel,
+        reason: Option<&str>,
+    ) -> Result<Channel> {
+        self.http_client
+            .patch(&format!("/channels/{}", channel_id.0))?
+            .json(channel)
+            .audit_log_reason(reason)
+            .send_json()
+            .await
+    }
+
+    /// Deletes a guild channel or thread, or closes a DM.
+    ///
+    /// https://discord.com/developers/docs/resources/channel#deleteclose-channel
+    pub async fn delete_channel(
+        &self,
+        channel_id: &ChannelId,
+        reason: Option<&str>,
+    ) -> Result<Channel> {
+        self.http_client
+            .delete(&format!("/channels/{}", channel_id.0))?
+            .audit_log_reason(reason)
+            .send_json()
+            .await
+    }
+
+    /// https://discord.com/developers/docs/resources/channel#edit-channel-permissions
+    pub async fn edit_channel_permissions(
+        &self,
+        channel_id: &ChannelId,
+        overwrite_id: &OverwriteId,
+        permissions: &requests::EditChannelPermissions,
+        reason: Option<&str>,
+    ) -> Result<()> {
+        self.http_client
+            .put(&format!(
+                "/channels/{}/permissions/{}",
+                channel_id.0, overwrite_id.0
+            ))?
+            .json(permissions)
+            .audit_log_reason(reason)
+            .send_empty()
+            .await
+    }
+
+    /// https://discord.com/developers/docs/resources/channel#delete-channel-permission
+    pub async fn delete_channel_permission(
+        &self,
+        channel_id: &ChannelId,
+        overwrite_id: &OverwriteId,
+        reason: Option<&str>,
+    ) -> Result<()> {
+        self.http_client
+            .delete(&format!(
+                "/channels/{}/permissions/{}",
+                channel_id.0, overwrite_id.0
+            ))?
+            .audit_log_reason(reason)
+            .send_empty()
+            .await
+    }
+
+    /// Follows an announcement channel, crossposting its messages to `webhook_channel_id`.
+    ///
+    /// https://discord.com/developers/docs/resources/channel#follow-announcement-channel
+    pub async fn follow_announcement_channel(
+        &self,
+        channel_id: &ChannelId,
+        webhook_channel_id: &ChannelId,
+        reason: Option<&str>,
+    ) -> Result<FollowedChannel> {
+        #[derive(Serialize)]
+        struct Body<'a> {
+            webhook_channel_id: &'a ChannelId,
+        }
+
+        self.http_client
+            .post(&format!("/channels/{}/followers", channel_id.0))?
+            .json(&Body { webhook_channel_id })
+            .audit_log_reason(reason)
+            .send_json()
+            .await
+    }
+
+    /// https://discord.com/developers/docs/resources/channel#trigger-typing-indicator
+    pub async fn trigger_typing_indicator(&self, channel_id: &ChannelId) -> Result<()> {
+        self.http_client
+            .post(&format!("/channels/{}/typing", channel_id.0))?
+            .send_empty()
+            .await
+    }
+}
+
+pub mod requests {
+    use serde::Serialize;
+
+    use crate::api::{
+        client::ImageData,
+        objects::{
+            channel::{
+                ChannelFlags, ChannelId, ChannelType, ForumLayoutType, ForumTagId, Overwrite,
+                OverwriteId, OverwriteType, SortOrderType, VideoQualityMode,
+            },
+            emoji::EmojiId,
+            permissions::Permissions,
+        },
+    };
+
+    /// The fields that can be modified depend on the type of channel.
+    /// Fields set to `None` are left as is, and fields with an `Option<Option<_>>` are cleared
+    /// when set to `Some(None)`.
+    ///
+    /// Discord docs: https://discord.com/developers/docs/resources/channel#modify-channel-json-params-group-dm
+    #[cfg_attr(feature = "clone", derive(Clone))]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    #[derive(Serialize)]
+    #[serde(untagged)]
+    pub enum ModifyChannel {
+        GroupDm(ModifyGroupDmChannel),
+        /// Text, announcement, and category channels.
+        GuildText(ModifyGuildTextChannel),
+        /// Voice and stage channels.
+        GuildVoice(ModifyGuildVoiceChannel),
+        /// Forum and media channels.
+        GuildForum(ModifyGuildForumChannel),
+        Thread(ModifyThread),
+    }
+
+    /// Discord docs: https://discord.com/developers/docs/resources/channel#modify-channel-json-params-group-dm
+    #[derive(Default)]
+    #[cfg_attr(feature = "clone", derive(Clone))]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    #[derive(Serialize)]
+    pub struct ModifyGroupDmChannel {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub name: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub icon: Option<ImageData>,
+    }
+
+    /// Categories only support `name`, `position`, and `permission_overwrites`.
+    ///
+    /// Discord docs: https://discord.com/developers/docs/resources/channel#modify-channel-json-params-guild-channel
+    #[derive(Default)]
+    #[cfg_attr(feature = "clone", derive(Clone))]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    #[derive(Serialize)]
+    pub struct ModifyGuildTextChannel {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub name: Option<String>,
+        /// Only conversion between text and announcement channels is supported,
+        /// in guilds with the `NEWS` feature.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub r#type: Option<ChannelType>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub position: Option<Option<i32>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub topic: Option<Option<String>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub nsfw: Option<bool>,
+        /// Seconds, 0-21600.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub rate_limit_per_user: Option<Option<u32>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub permission_overwrites: Option<Vec<PartialOverwrite>>,
+        /// Category the channel is in.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub parent_id: Option<Option<ChannelId>>,
+        /// Minutes.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub default_auto_archive_duration: Option<Option<u32>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub default_thread_rate_limit_per_user: Option<u32>,
+    }
+
+    /// Discord docs: https://discord.com/developers/docs/resources/channel#modify-channel-json-params-guild-channel
+    #[derive(Default)]
+    #[cfg_attr(feature = "clone", derive(Clone))]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    #[derive(Serialize)]
+    pub struct ModifyGuildVoiceChannel {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub name: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub position: Option<Option<i32>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub nsfw: Option<bool>,
+        /// Seconds, 0-21600.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub rate_limit_per_user: Option<Option<u32>>,
+        /// Bits per second, minimum 8000.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub bitrate: Option<Option<u32>>,
+        /// 0 is unlimited.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub user_limit: Option<Option<u32>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub permission_overwrites: Option<Vec<PartialOverwrite>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub parent_id: Option<Option<ChannelId>>,
+        /// Set to `Some(None)` for automatic.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub rtc_region: Option<Option<String>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub video_quality_mode: Option<Option<VideoQualityMode>>,
+    }
+
+    /// Discord docs: https://discord.com/developers/docs/resources/channel#modify-channel-json-params-guild-channel
+    #[derive(Default)]
+    #[cfg_attr(feature = "clone", derive(Clone))]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    #[derive(Serialize)]
+    pub struct ModifyGuildForumChannel {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub name: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub position: Option<Option<i32>>,
+        /// Guidelines shown when creating a post.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub topic: Option<Option<String>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub nsfw: Option<bool>,
+        /// Seconds, 0-21600, between creating posts.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub rate_limit_per_user: Option<Option<u32>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub permission_overwrites: Option<Vec<PartialOverwrite>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub parent_id: Option<Option<ChannelId>>,
+        /// Minutes.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub default_auto_archive_duration: Option<Option<u32>>,
+        /// Only `REQUIRE_TAG` (and `HIDE_MEDIA_DOWNLOAD_OPTIONS` for media channels) can be set.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub flags: Option<ChannelFlags>,
+        /// Up to 20 tags. Tags without an ID are created.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub available_tags: Option<Vec<EditForumTag>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub default_reaction_emoji: Option<Option<EditDefaultReaction>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub default_thread_rate_limit_per_user: Option<u32>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub default_sort_order: Option<Option<SortOrderType>>,
+        /// Forum channels only.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub default_forum_layout: Option<ForumLayoutType>,
+    }
+
+    /// Discord docs: https://discord.com/developers/docs/resources/channel#modify-channel-json-params-thread
+    #[derive(Default)]
+    #[cfg_attr(feature = "clone", derive(Clone))]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    #[derive(Serialize)]
+    pub struct ModifyThread {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub name: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub archived: Option<bool>,
+        /// Minutes of inactivity before the thread is archived: 60, 1440, 4320, or 10080.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub auto_archive_duration: Option<u32>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub locked: Option<bool>,
+        /// Private threads only.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub invitable: Option<bool>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub rate_limit_per_user: Option<Option<u32>>,
+        /// Only `PINNED` can be set, for threads in forum and media channels.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub flags: Option<ChannelFlags>,
+        /// Up to 5 tags from the parent forum or media channel.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub applied_tags: Option<Vec<ForumTagId>>,
+    }
+
+    /// Overwrite where `allow` and `deny` default to no permissions.
+    ///
+    /// Discord docs: https://discord.com/developers/docs/resources/channel#overwrite-object
+    #[cfg_attr(feature = "clone", derive(Clone))]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    #[derive(Serialize)]
+    pub struct PartialOverwrite {
+        pub id: OverwriteId,
+        pub r#type: OverwriteType,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub allow: Option<Permissions>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub deny: Option<Permissions>,
+    }
+
+    impl From<Overwrite> for PartialOverwrite {
+        fn from(value: Overwrite) -> Self {
+            PartialOverwrite {
+                id: value.id,
+                r#type: value.r#type,
+                allow: Some(value.allow),
+                deny: Some(value.deny),
+            }
+        }
+    }
+
+    /// Discord docs: https://discord.com/developers/docs/resources/channel#forum-tag-object
+    #[cfg_attr(feature = "clone", derive(Clone))]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    #[derive(Serialize)]
+    pub struct EditForumTag {
+        /// `None` to create a new tag.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub id: Option<ForumTagId>,
+        pub name: String,
+        pub moderated: bool,
+        pub emoji_id: Option<EmojiId>,
+        pub emoji_name: Option<String>,
+    }
+
+    /// Set either the ID of a custom emoji or the unicode emoji.
+    ///
+    /// Discord docs: https://discord.com/developers/docs/resources/channel#default-reaction-object
+    #[cfg_attr(feature = "clone", derive(Clone))]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    #[derive(Serialize)]
+    pub struct EditDefaultReaction {
+        pub emoji_id: Option<EmojiId>,
+        pub emoji_name: Option<String>,
+    }
+
+    /// Discord docs: https://discord.com/developers/docs/resources/channel#edit-channel-permissions-json-params
+    #[cfg_attr(feature = "clone", derive(Clone))]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    #[derive(Serialize)]
+    pub struct EditChannelPermissions {
+        /// Defaults to no permissions.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub allow: Option<Permissions>,
+        /// Defaults to no permissions.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub deny: Option<Permissions>,
+        pub r#type: OverwriteType,
+    }
+}
+
+#[test]
+fn test_modify_channel() {
+    use crate::{
+        api::objects::{channel::OverwriteType, permissions::permissions},
+        flags,
+    };
+
+    let channel = requests::ModifyChannel::GuildText(requests::ModifyGuildTextChannel {
+        name: Some(String::from("rules")),
+        topic: Some(None),
+        permission_overwrites: Some(vec![requests::PartialOverwrite {
+            id: OverwriteId(String::from("1234")),
+            r#type: OverwriteType::Role,
+            allow: None,
+            deny: Some(flags!(permissions(SendMessages))),
+        }]),
+        ..Default::default()
+    });
+
+    assert_eq!(
+        r#"{"name":"rules","topic":null,"permission_overwrites":[{"id":"1234","type":0,"deny":"2048"}]}"#,
+        serde_json::to_string(&channel).unwrap()
+    );
+}
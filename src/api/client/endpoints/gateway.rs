@@ -1,5 +1,4 @@
-use std::collections::HashMap;
-use std::rc::Rc;
+use std::sync::Arc;
 
 use reqwest::StatusCode;
 
@@ -7,25 +6,23 @@ use crate::api::client::http_client::HttpClient;
 
 use crate::api::client::{ApiError, Result};
 
+#[derive(Clone)]
 pub struct Gateway {
-    http_client: Rc<HttpClient>,
+    http_client: Arc<HttpClient>,
 }
 
 impl Gateway {
-    pub fn new(http_client: Rc<HttpClient>) -> Self {
+    pub fn new(http_client: Arc<HttpClient>) -> Self {
         Gateway { http_client }
     }
 
     /// https://discord.com/developers/docs/topics/gateway#get-gateway
     pub async fn get_gateway(&self) -> Result<responses::Gateway> {
-        let response = self.http_client.get("/gateway", &HashMap::new()).await?;
+        let response = self.http_client.get("/gateway")?.send().await?;
 
         match response.status() {
             StatusCode::OK => Ok(response.json::<responses::Gateway>().await?),
-            status_code => Err(ApiError::UnexpectedResponse {
-                status_code,
-                response: response.text().await?,
-            }),
+            _ => Err(ApiError::from_response(response).await),
         }
     }
 }
@@ -0,0 +1,426 @@
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+
+use crate::api::{
+    client::{http_client::HttpClient, Result},
+    objects::{
+        channel::Channel,
+        guild::{Guild, GuildId, GuildPreview, GuildPruneCount, GuildVanityUrl, MfaLevel},
+    },
+};
+
+#[derive(Clone)]
+pub struct Guilds {
+    http_client: Arc<HttpClient>,
+}
+
+impl Guilds {
+    pub fn new(http_client: Arc<HttpClient>) -> Self {
+        Guilds { http_client }
+    }
+
+    /// Only available to bots in fewer than 10 guilds.
+    ///
+    /// https://discord.com/developers/docs/resources/guild#create-guild
+    pub async fn create_guild(&self, guild: &requests::CreateGuild) -> Result<Guild> {
+        self.http_client
+            .post("/guilds")?
+            .json(guild)
+            .send_json()
+            .await
+    }
+
+    /// Sets `approximate_member_count` and `approximate_presence_count` when `with_counts` is `true`.
+    ///
+    /// https://discord.com/developers/docs/resources/guild#get-guild
+    pub async fn get_guild(&self, guild_id: &GuildId, with_counts: bool) -> Result<Guild> {
+        #[derive(Serialize)]
+        struct Query {
+            with_counts: bool,
+        }
+
+        self.http_client
+            .get(&format!("/guilds/{}", guild_id.0))?
+            .query(&Query { with_counts })
+            .send_json()
+            .await
+    }
+
+    /// Only available for guilds the bot is in, or that are discoverable.
+    ///
+    /// https://discord.com/developers/docs/resources/guild#get-guild-preview
+    pub async fn get_guild_preview(&self, guild_id: &GuildId) -> Result<GuildPreview> {
+        self.http_client
+            .get(&format!("/guilds/{}/preview", guild_id.0))?
+            .send_json()
+            .await
+    }
+
+    /// https://discord.com/developers/docs/resources/guild#modify-guild
+    pub async fn modify_guild(
+        &self,
+        guild_id: &GuildId,
+        guild: &requests::ModifyGuild,
+        reason: Option<&str>,
+    ) -> Result<Guild> {
+        self.http_client
+            .patch(&format!("/guilds/{}", guild_id.0))?
+            .json(guild)
+            .audit_log_reason(reason)
+            .send_json()
+            .await
+    }
+
+    /// Only available to the owner of the guild.
+    ///
+    /// https://discord.com/developers/docs/resources/guild#delete-guild
+    pub async fn delete_guild(&self, guild_id: &GuildId) -> Result<()> {
+        self.http_client
+            .delete(&format!("/guilds/{}", guild_id.0))?
+            .send_empty()
+            .await
+    }
+
+    /// Channels in the guild, not including threads.
+    ///
+    /// https://discord.com/developers/docs/resources/guild#get-guild-channels
+    pub async fn get_guild_channels(&self, guild_id: &GuildId) -> Result<Vec<Channel>> {
+        self.http_client
+            .get(&format!("/guilds/{}/channels", guild_id.0))?
+            .send_json()
+            .await
+    }
+
+    /// https://discord.com/developers/docs/resources/guild#create-guild-channel
+    pub async fn create_guild_channel(
+        &self,
+        guild_id: &GuildId,
+        channel: &requests::CreateGuildChannel,
+        reason: Option<&str>,
+    ) -> Result<Channel> {
+        self.http_client
+            .post(&format!("/guilds/{}/channels", guild_id.0))?
+            .json(channel)
+            .audit_log_reason(reason)
+            .send_json()
+            .await
+    }
+
+    /// https://discord.com/developers/docs/resources/guild#modify-guild-channel-positions
+    pub async fn modify_guild_channel_positions(
+        &self,
+        guild_id: &GuildId,
+        positions: &[requests::ModifyGuildChannelPosition],
+    ) -> Result<()> {
+        self.http_client
+            .patch(&format!("/guilds/{}/channels", guild_id.0))?
+            .json(positions)
+            .send_empty()
+            .await
+    }
+
+    /// Number of members that would be removed by a prune.
+    ///
+    /// https://discord.com/developers/docs/resources/guild#get-guild-prune-count
+    pub async fn get_guild_prune_count(
+        &self,
+        guild_id: &GuildId,
+        query: &requests::GetGuildPruneCount,
+    ) -> Result<GuildPruneCount> {
+        self.http_client
+            .get(&format!("/guilds/{}/prune", guild_id.0))?
+            .query(query)
+            .send_json()
+            .await
+    }
+
+    /// Removes members that have been inactive for `days`,
+    /// not counting those with roles unless they're in `include_roles`.
+    ///
+    /// https://discord.com/developers/docs/resources/guild#begin-guild-prune
+    pub async fn begin_guild_prune(
+        &self,
+        guild_id: &GuildId,
+        prune: &requests::BeginGuildPrune,
+        reason: Option<&str>,
+    ) -> Result<GuildPruneCount> {
+        self.http_client
+            .post(&format!("/guilds/{}/prune", guild_id.0))?
+            .json(prune)
+            .audit_log_reason(reason)
+            .send_json()
+            .await
+    }
+
+    /// https://discord.com/developers/docs/resources/guild#get-guild-vanity-url
+    pub async fn get_guild_vanity_url(&self, guild_id: &GuildId) -> Result<GuildVanityUrl> {
+        self.http_client
+            .get(&format!("/guilds/{}/vanity-url", guild_id.0))?
+            .send_json()
+            .await
+    }
+
+    /// Only available to the owner of the guild.
+    ///
+    /// https://discord.com/developers/docs/resources/guild#modify-guild-mfa-level
+    pub async fn modify_guild_mfa_level(
+        &self,
+        guild_id: &GuildId,
+        level: MfaLevel,
+        reason: Option<&str>,
+    ) -> Result<MfaLevel> {
+        // Sent and returned as an object rather than just the level.
+        #[derive(Deserialize, Serialize)]
+        struct Mfa {
+            level: MfaLevel,
+        }
+
+        let mfa: Mfa = self
+            .http_client
+            .post(&format!("/guilds/{}/mfa", guild_id.0))?
+            .json(&Mfa { level })
+            .audit_log_reason(reason)
+            .send_json()
+            .await?;
+        Ok(mfa.level)
+    }
+}
+
+pub mod requests {
+    use serde::{Serialize, Serializer};
+
+    use crate::api::{
+        client::{
+            endpoints::channels::requests::{EditDefaultReaction, EditForumTag, PartialOverwrite},
+            ImageData,
+        },
+        objects::{
+            channel::{ChannelId, ChannelType, ForumLayoutType, SortOrderType, VideoQualityMode},
+            guild::{
+                DefaultMessageNotificationLevel, ExplicitContentFilterLevel, GuildFeature,
+                SystemChannelFlags, VerificationLevel,
+            },
+            permissions::RoleId,
+            user::UserId,
+        },
+    };
+
+    /// Discord docs: https://discord.com/developers/docs/resources/guild#create-guild-json-params
+    #[derive(Default)]
+    #[cfg_attr(feature = "clone", derive(Clone))]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    #[derive(Serialize)]
+    pub struct CreateGuild {
+        /// 2-100 characters.
+        pub name: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub icon: Option<ImageData>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub verification_level: Option<VerificationLevel>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub default_message_notifications: Option<DefaultMessageNotificationLevel>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub explicit_content_filter: Option<ExplicitContentFilterLevel>,
+        /// Channels to create instead of the defaults. The `id` of each channel is a placeholder
+        /// used by `parent_id`, `afk_channel_id`, and `system_channel_id`.
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        pub channels: Vec<CreateGuildChannel>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub afk_channel_id: Option<ChannelId>,
+        /// Seconds.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub afk_timeout: Option<u32>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub system_channel_id: Option<ChannelId>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub system_channel_flags: Option<SystemChannelFlags>,
+    }
+
+    /// Fields set to `None` are left as is, and fields with an `Option<Option<_>>` are cleared
+    /// when set to `Some(None)`.
+    ///
+    /// Discord docs: https://discord.com/developers/docs/resources/guild#modify-guild-json-params
+    #[derive(Default)]
+    #[cfg_attr(feature = "clone", derive(Clone))]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    #[derive(Serialize)]
+    pub struct ModifyGuild {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub name: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub verification_level: Option<Option<VerificationLevel>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub default_message_notifications: Option<Option<DefaultMessageNotificationLevel>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub explicit_content_filter: Option<Option<ExplicitContentFilterLevel>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub afk_channel_id: Option<Option<ChannelId>>,
+        /// Seconds.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub afk_timeout: Option<u32>,
+        /// Animated icons need the `ANIMATED_ICON` feature.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub icon: Option<Option<ImageData>>,
+        /// Transfers ownership of the guild. Only available to the owner.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub owner_id: Option<UserId>,
+        /// Needs the `INVITE_SPLASH` feature.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub splash: Option<Option<ImageData>>,
+        /// Needs the `DISCOVERABLE` feature.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub discovery_splash: Option<Option<ImageData>>,
+        /// Needs the `BANNER` feature, and `ANIMATED_BANNER` for animated banners.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub banner: Option<Option<ImageData>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub system_channel_id: Option<Option<ChannelId>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub system_channel_flags: Option<SystemChannelFlags>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub rules_channel_id: Option<Option<ChannelId>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub public_updates_channel_id: Option<Option<ChannelId>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub preferred_locale: Option<Option<String>>,
+        /// Only features such as `COMMUNITY` and `INVITES_DISABLED` can be enabled or disabled.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub features: Option<Vec<GuildFeature>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub description: Option<Option<String>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub premium_progress_bar_enabled: Option<bool>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub safety_alerts_channel_id: Option<Option<ChannelId>>,
+    }
+
+    /// Fields that don't apply to the type of channel are ignored.
+    ///
+    /// Discord docs: https://discord.com/developers/docs/resources/guild#create-guild-channel-json-params
+    #[derive(Default)]
+    #[cfg_attr(feature = "clone", derive(Clone))]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    #[derive(Serialize)]
+    pub struct CreateGuildChannel {
+        /// Placeholder ID, only used by Create Guild.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub id: Option<ChannelId>,
+        /// 1-100 characters.
+        pub name: String,
+        /// Defaults to `GuildText`.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub r#type: Option<ChannelType>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub topic: Option<String>,
+        /// Bits per second, minimum 8000.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub bitrate: Option<u32>,
+        /// 0 is unlimited.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub user_limit: Option<u32>,
+        /// Seconds, 0-21600.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub rate_limit_per_user: Option<u32>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub position: Option<i32>,
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        pub permission_overwrites: Vec<PartialOverwrite>,
+        /// Category to create the channel in.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub parent_id: Option<ChannelId>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub nsfw: Option<bool>,
+        /// Voice region, automatic when `None`.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub rtc_region: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub video_quality_mode: Option<VideoQualityMode>,
+        /// Minutes.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub default_auto_archive_duration: Option<u32>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub default_reaction_emoji: Option<EditDefaultReaction>,
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        pub available_tags: Vec<EditForumTag>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub default_sort_order: Option<SortOrderType>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub default_forum_layout: Option<ForumLayoutType>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub default_thread_rate_limit_per_user: Option<u32>,
+    }
+
+    /// Discord docs: https://discord.com/developers/docs/resources/guild#modify-guild-channel-positions-json-params
+    #[cfg_attr(feature = "clone", derive(Clone))]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    #[derive(Serialize)]
+    pub struct ModifyGuildChannelPosition {
+        pub id: ChannelId,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub position: Option<Option<i32>>,
+        /// Whether to sync permissions with the new parent, when moving to a new category.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub lock_permissions: Option<Option<bool>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub parent_id: Option<Option<ChannelId>>,
+    }
+
+    /// Discord docs: https://discord.com/developers/docs/resources/guild#get-guild-prune-count-query-string-params
+    #[derive(Default)]
+    #[cfg_attr(feature = "clone", derive(Clone))]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    #[derive(Serialize)]
+    pub struct GetGuildPruneCount {
+        /// 1-30, defaults to 7.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub days: Option<u8>,
+        /// Roles members can have and still be pruned.
+        #[serde(
+            skip_serializing_if = "Vec::is_empty",
+            serialize_with = "comma_separated"
+        )]
+        pub include_roles: Vec<RoleId>,
+    }
+
+    /// Discord docs: https://discord.com/developers/docs/resources/guild#begin-guild-prune-json-params
+    #[derive(Default)]
+    #[cfg_attr(feature = "clone", derive(Clone))]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    #[derive(Serialize)]
+    pub struct BeginGuildPrune {
+        /// 1-30, defaults to 7.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub days: Option<u8>,
+        /// Whether to return the number of pruned members, which Discord discourages for large guilds.
+        /// Defaults to `true`.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub compute_prune_count: Option<bool>,
+        /// Roles members can have and still be pruned.
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        pub include_roles: Vec<RoleId>,
+    }
+
+    fn comma_separated<S: Serializer>(ids: &[RoleId], serializer: S) -> Result<S::Ok, S::Error> {
+        let ids: Vec<&str> = ids.iter().map(|id| id.0.as_str()).collect();
+        serializer.serialize_str(&ids.join(","))
+    }
+}
+
+#[test]
+fn test_get_guild_prune_count() {
+    use crate::api::objects::permissions::RoleId;
+
+    let url = reqwest::Client::new()
+        .get("https://discord.com/api/v10/guilds/1/prune")
+        .query(&requests::GetGuildPruneCount {
+            days: Some(30),
+            include_roles: vec![RoleId(String::from("12")), RoleId(String::from("34"))],
+        })
+        .build()
+        .unwrap()
+        .url()
+        .clone();
+
+    assert_eq!(Some("days=30&include_roles=12%2C34"), url.query());
+}
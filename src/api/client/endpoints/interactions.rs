@@ -0,0 +1,395 @@
+use std::sync::Arc;
+
+use reqwest::StatusCode;
+use serde::Serialize;
+
+use crate::api::{
+    client::{
+        endpoints::webhooks::requests::EditWebhookMessage, http_client::HttpClient, ApiError,
+        AttachmentFile, Result,
+    },
+    objects::{
+        application::ApplicationId,
+        channel::{Message, MessageId},
+        interactions::{InteractionCallbackResponse, InteractionId},
+    },
+};
+
+/// Responses to interactions, authenticated by the interaction's token rather than the bot's.
+/// Tokens are valid for 15 minutes, though the initial response has to be sent within 3 seconds.
+#[derive(Clone)]
+pub struct Interactions {
+    http_client: Arc<HttpClient>,
+}
+
+impl Interactions {
+    pub fn new(http_client: Arc<HttpClient>) -> Self {
+        Interactions { http_client }
+    }
+
+    /// Responds to an interaction, uploading `files` as attachments to the message.
+    /// Returns the interaction callback when `with_response` is `true`.
+    ///
+    /// https://discord.com/developers/docs/interactions/receiving-and-responding#create-interaction-response
+    pub async fn create_interaction_response(
+        &self,
+        interaction_id: &InteractionId,
+        interaction_token: &str,
+        response: &requests::InteractionResponse,
+        files: Vec<AttachmentFile>,
+        with_response: bool,
+    ) -> Result<Option<InteractionCallbackResponse>> {
+        #[derive(Serialize)]
+        struct Query {
+            with_response: bool,
+        }
+
+        let response = self
+            .http_client
+            .post(&format!(
+                "/interactions/{}/{}/callback",
+                interaction_id.0, interaction_token
+            ))?
+            .query(&Query { with_response })
+            .json_with_nested_files(response, "data", files)?
+            .send()
+            .await?;
+        if response.status() == StatusCode::NO_CONTENT {
+            return Ok(None);
+        }
+        if !response.status().is_success() {
+            return Err(ApiError::from_response(response).await);
+        }
+
+        Ok(Some(response.json().await?))
+    }
+
+    /// https://discord.com/developers/docs/interactions/receiving-and-responding#get-original-interaction-response
+    pub async fn get_original_interaction_response(
+        &self,
+        application_id: &ApplicationId,
+        interaction_token: &str,
+    ) -> Result<Message> {
+        self.http_client
+            .get(&format!(
+                "/webhooks/{}/{}/messages/@original",
+                application_id.0, interaction_token
+            ))?
+            .send_json()
+            .await
+    }
+
+    /// Edits the initial response, uploading `files` as new attachments.
+    ///
+    /// https://discord.com/developers/docs/interactions/receiving-and-responding#edit-original-interaction-response
+    pub async fn edit_original_interaction_response(
+        &self,
+        application_id: &ApplicationId,
+        interaction_token: &str,
+        message: &EditWebhookMessage,
+        files: Vec<AttachmentFile>,
+    ) -> Result<Message> {
+        self.http_client
+            .patch(&format!(
+                "/webhooks/{}/{}/messages/@original",
+                application_id.0, interaction_token
+            ))?
+            .json_with_files(message, files)?
+            .send_json()
+            .await
+    }
+
+    /// https://discord.com/developers/docs/interactions/receiving-and-responding#delete-original-interaction-response
+    pub async fn delete_original_interaction_response(
+        &self,
+        application_id: &ApplicationId,
+        interaction_token: &str,
+    ) -> Result<()> {
+        self.http_client
+            .delete(&format!(
+                "/webhooks/{}/{}/messages/@original",
+                application_id.0, interaction_token
+            ))?
+            .send_empty()
+            .await
+    }
+
+    /// Sends another message for the interaction, uploading `files` as attachments.
+    /// Edits the initial response instead if it was deferred.
+    ///
+    /// https://discord.com/developers/docs/interactions/receiving-and-responding#create-followup-message
+    pub async fn create_followup_message(
+        &self,
+        application_id: &ApplicationId,
+        interaction_token: &str,
+        message: &requests::CreateFollowupMessage,
+        files: Vec<AttachmentFile>,
+    ) -> Result<Message> {
+        self.http_client
+            .post(&format!(
+                "/webhooks/{}/{}",
+                application_id.0, interaction_token
+            ))?
+            .json_with_files(message, files)?
+            .send_json()
+            .await
+    }
+
+    /// https://discord.com/developers/docs/interactions/receiving-and-responding#get-followup-message
+    pub async fn get_followup_message(
+        &self,
+        application_id: &ApplicationId,
+        interaction_token: &str,
+        message_id: &MessageId,
+    ) -> Result<Message> {
+        self.http_client
+            .get(&format!(
+                "/webhooks/{}/{}/messages/{}",
+                application_id.0, interaction_token, message_id.0
+            ))?
+            .send_json()
+            .await
+    }
+
+    /// Edits a followup message, uploading `files` as new attachments.
+    ///
+    /// https://discord.com/developers/docs/interactions/receiving-and-responding#edit-followup-message
+    pub async fn edit_followup_message(
+        &self,
+        application_id: &ApplicationId,
+        interaction_token: &str,
+        message_id: &MessageId,
+        message: &EditWebhookMessage,
+        files: Vec<AttachmentFile>,
+    ) -> Result<Message> {
+        self.http_client
+            .patch(&format!(
+                "/webhooks/{}/{}/messages/{}",
+                application_id.0, interaction_token, message_id.0
+            ))?
+            .json_with_files(message, files)?
+            .send_json()
+            .await
+    }
+
+    /// https://discord.com/developers/docs/interactions/receiving-and-responding#delete-followup-message
+    pub async fn delete_followup_message(
+        &self,
+        application_id: &ApplicationId,
+        interaction_token: &str,
+        message_id: &MessageId,
+    ) -> Result<()> {
+        self.http_client
+            .delete(&format!(
+                "/webhooks/{}/{}/messages/{}",
+                application_id.0, interaction_token, message_id.0
+            ))?
+            .send_empty()
+            .await
+    }
+}
+
+pub mod requests {
+    use serde::{Serialize, Serializer};
+
+    use crate::api::{
+        client::endpoints::messages::requests::PartialAttachment,
+        objects::{
+            application_command::ApplicationCommandOptionChoice,
+            channel::{AllowedMentions, Embed, MessageFlags},
+            interactions::InteractionCallbackType,
+            message_components::Component,
+            poll::PollCreateRequest,
+        },
+    };
+
+    /// Discord docs: https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-response-object
+    #[cfg_attr(feature = "clone", derive(Clone))]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    pub enum InteractionResponse {
+        /// Acknowledges a `Ping`.
+        Pong,
+        ChannelMessageWithSource(InteractionCallbackMessage),
+        /// Shows a loading state, to be replaced by editing the original response.
+        /// Only `flags` is used, such as to make the response ephemeral.
+        DeferredChannelMessageWithSource(InteractionCallbackMessage),
+        /// Acknowledges a component interaction, to edit its message later.
+        DeferredUpdateMessage,
+        /// Edits the message of a component interaction.
+        UpdateMessage(InteractionCallbackMessage),
+        /// Up to 25 choices for an autocomplete interaction.
+        ApplicationCommandAutocompleteResult(Vec<ApplicationCommandOptionChoice>),
+        Modal(InteractionCallbackModal),
+        /// Launches the application's activity. Only for apps with activities enabled.
+        LaunchActivity,
+    }
+
+    impl InteractionResponse {
+        pub fn callback_type(&self) -> InteractionCallbackType {
+            match self {
+                InteractionResponse::Pong => InteractionCallbackType::Pong,
+                InteractionResponse::ChannelMessageWithSource(_) => {
+                    InteractionCallbackType::ChannelMessageWithSource
+                }
+                InteractionResponse::DeferredChannelMessageWithSource(_) => {
+                    InteractionCallbackType::DeferredChannelMessageWithSource
+                }
+                InteractionResponse::DeferredUpdateMessage => {
+                    InteractionCallbackType::DeferredUpdateMessage
+                }
+                InteractionResponse::UpdateMessage(_) => InteractionCallbackType::UpdateMessage,
+                InteractionResponse::ApplicationCommandAutocompleteResult(_) => {
+                    InteractionCallbackType::ApplicationCommandAutocompleteResult
+                }
+                InteractionResponse::Modal(_) => InteractionCallbackType::Modal,
+                InteractionResponse::LaunchActivity => InteractionCallbackType::LaunchActivity,
+            }
+        }
+    }
+
+    impl Serialize for InteractionResponse {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            #[derive(Serialize)]
+            struct Response<T> {
+                r#type: InteractionCallbackType,
+                #[serde(skip_serializing_if = "Option::is_none")]
+                data: Option<T>,
+            }
+
+            #[derive(Serialize)]
+            struct Choices<'a> {
+                choices: &'a [ApplicationCommandOptionChoice],
+            }
+
+            let r#type = self.callback_type();
+            match self {
+                InteractionResponse::Pong
+                | InteractionResponse::DeferredUpdateMessage
+                | InteractionResponse::LaunchActivity => {
+                    Response::<()> { r#type, data: None }.serialize(serializer)
+                }
+                InteractionResponse::ChannelMessageWithSource(message)
+                | InteractionResponse::DeferredChannelMessageWithSource(message)
+                | InteractionResponse::UpdateMessage(message) => Response {
+                    r#type,
+                    data: Some(message),
+                }
+                .serialize(serializer),
+                InteractionResponse::ApplicationCommandAutocompleteResult(choices) => Response {
+                    r#type,
+                    data: Some(Choices { choices }),
+                }
+                .serialize(serializer),
+                InteractionResponse::Modal(modal) => Response {
+                    r#type,
+                    data: Some(modal),
+                }
+                .serialize(serializer),
+            }
+        }
+    }
+
+    /// Discord docs: https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-response-object-messages
+    #[derive(Default)]
+    #[cfg_attr(feature = "clone", derive(Clone))]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    #[derive(Serialize)]
+    pub struct InteractionCallbackMessage {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub tts: Option<bool>,
+        /// Up to 2000 characters.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub content: Option<String>,
+        /// Up to 10 embeds.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub embeds: Option<Vec<Embed>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub allowed_mentions: Option<AllowedMentions>,
+        /// Only `SUPPRESS_EMBEDS`, `EPHEMERAL`, and `SUPPRESS_NOTIFICATIONS` can be set.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub flags: Option<MessageFlags>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub components: Option<Vec<Component>>,
+        /// Existing attachments to keep when updating a message.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub attachments: Option<Vec<PartialAttachment>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub poll: Option<PollCreateRequest>,
+    }
+
+    /// Discord docs: https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-response-object-modal
+    #[cfg_attr(feature = "clone", derive(Clone))]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    #[derive(Serialize)]
+    pub struct InteractionCallbackModal {
+        pub custom_id: String,
+        /// Up to 45 characters.
+        pub title: String,
+        /// 1-5 action rows of text inputs.
+        pub components: Vec<Component>,
+    }
+
+    /// Discord docs: https://discord.com/developers/docs/interactions/receiving-and-responding#create-followup-message
+    #[derive(Default)]
+    #[cfg_attr(feature = "clone", derive(Clone))]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    #[derive(Serialize)]
+    pub struct CreateFollowupMessage {
+        /// Up to 2000 characters.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub content: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub tts: Option<bool>,
+        /// Up to 10 embeds.
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        pub embeds: Vec<Embed>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub allowed_mentions: Option<AllowedMentions>,
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        pub components: Vec<Component>,
+        /// Only `SUPPRESS_EMBEDS`, `EPHEMERAL`, and `SUPPRESS_NOTIFICATIONS` can be set.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub flags: Option<MessageFlags>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub poll: Option<PollCreateRequest>,
+    }
+}
+
+#[test]
+fn test_interaction_response() {
+    use crate::api::objects::{
+        application_command::{ApplicationCommandOptionChoice, ChoiceName, ChoiceValue},
+        channel::message_flags,
+    };
+
+    let response = requests::InteractionResponse::ChannelMessageWithSource(
+        requests::InteractionCallbackMessage {
+            content: Some(String::from("Pong!")),
+            flags: Some(message_flags::Flags::new(
+                [message_flags::Flag::Ephemeral].into_iter(),
+            )),
+            ..Default::default()
+        },
+    );
+    assert_eq!(
+        r#"{"type":4,"data":{"content":"Pong!","flags":64}}"#,
+        serde_json::to_string(&response).unwrap()
+    );
+
+    let response = requests::InteractionResponse::ApplicationCommandAutocompleteResult(vec![
+        ApplicationCommandOptionChoice {
+            name: ChoiceName(String::from("Ferris")),
+            name_localizations: None,
+            value: ChoiceValue::String(String::from("ferris")),
+        },
+    ]);
+    assert_eq!(
+        r#"{"type":8,"data":{"choices":[{"name":"Ferris","value":"ferris"}]}}"#,
+        serde_json::to_string(&response).unwrap()
+    );
+
+    assert_eq!(
+        r#"{"type":6}"#,
+        serde_json::to_string(&requests::InteractionResponse::DeferredUpdateMessage).unwrap()
+    );
+}
@@ -0,0 +1,322 @@
+use std::sync::Arc;
+
+use reqwest::StatusCode;
+use serde::Serialize;
+
+use crate::api::{
+    client::{http_client::HttpClient, ApiError, Result},
+    objects::{
+        guild::{GuildId, GuildMember},
+        permissions::RoleId,
+        user::UserId,
+    },
+};
+
+#[derive(Clone)]
+pub struct Members {
+    http_client: Arc<HttpClient>,
+}
+
+impl Members {
+    pub fn new(http_client: Arc<HttpClient>) -> Self {
+        Members { http_client }
+    }
+
+    /// https://discord.com/developers/docs/resources/guild#get-guild-member
+    pub async fn get_guild_member(
+        &self,
+        guild_id: &GuildId,
+        user_id: &UserId,
+    ) -> Result<GuildMember> {
+        self.http_client
+            .get(&format!("/guilds/{}/members/{}", guild_id.0, user_id.0))?
+            .send_json()
+            .await
+    }
+
+    /// Members ordered by user ID. Needs the `GUILD_MEMBERS` privileged intent.
+    ///
+    /// https://discord.com/developers/docs/resources/guild#list-guild-members
+    pub async fn list_guild_members(
+        &self,
+        guild_id: &GuildId,
+        query: &requests::ListGuildMembers,
+    ) -> Result<Vec<GuildMember>> {
+        self.http_client
+            .get(&format!("/guilds/{}/members", guild_id.0))?
+            .query(query)
+            .send_json()
+            .await
+    }
+
+    /// Members whose username or nickname starts with `query`.
+    ///
+    /// https://discord.com/developers/docs/resources/guild#search-guild-members
+    pub async fn search_guild_members(
+        &self,
+        guild_id: &GuildId,
+        query: &requests::SearchGuildMembers,
+    ) -> Result<Vec<GuildMember>> {
+        self.http_client
+            .get(&format!("/guilds/{}/members/search", guild_id.0))?
+            .query(query)
+            .send_json()
+            .await
+    }
+
+    /// Adds a user to the guild with an OAuth2 access token that has the `guilds.join` scope.
+    /// Returns `None` when the user is already a member.
+    ///
+    /// https://discord.com/developers/docs/resources/guild#add-guild-member
+    pub async fn add_guild_member(
+        &self,
+        guild_id: &GuildId,
+        user_id: &UserId,
+        member: &requests::AddGuildMember,
+    ) -> Result<Option<GuildMember>> {
+        let response = self
+            .http_client
+            .put(&format!("/guilds/{}/members/{}", guild_id.0, user_id.0))?
+            .json(member)
+            .send()
+            .await?;
+        if response.status() == StatusCode::NO_CONTENT {
+            return Ok(None);
+        }
+        if !response.status().is_success() {
+            return Err(ApiError::from_response(response).await);
+        }
+
+        Ok(Some(response.json().await?))
+    }
+
+    /// https://discord.com/developers/docs/resources/guild#modify-guild-member
+    pub async fn modify_guild_member(
+        &self,
+        guild_id: &GuildId,
+        user_id: &UserId,
+        member: &requests::ModifyGuildMember,
+        reason: Option<&str>,
+    ) -> Result<GuildMember> {
+        self.http_client
+            .patch(&format!("/guilds/{}/members/{}", guild_id.0, user_id.0))?
+            .json(member)
+            .audit_log_reason(reason)
+            .send_json()
+            .await
+    }
+
+    /// Sets the nickname of the current user.
+    ///
+    /// https://discord.com/developers/docs/resources/guild#modify-current-member
+    pub async fn modify_current_member(
+        &self,
+        guild_id: &GuildId,
+        nick: Option<&str>,
+        reason: Option<&str>,
+    ) -> Result<GuildMember> {
+        #[derive(Serialize)]
+        struct Body<'a> {
+            nick: Option<&'a str>,
+        }
+
+        self.http_client
+            .patch(&format!("/guilds/{}/members/@me", guild_id.0))?
+            .json(&Body { nick })
+            .audit_log_reason(reason)
+            .send_json()
+            .await
+    }
+
+    /// https://discord.com/developers/docs/resources/guild#add-guild-member-role
+    pub async fn add_guild_member_role(
+        &self,
+        guild_id: &GuildId,
+        user_id: &UserId,
+        role_id: &RoleId,
+        reason: Option<&str>,
+    ) -> Result<()> {
+        self.http_client
+            .put(&format!(
+                "/guilds/{}/members/{}/roles/{}",
+                guild_id.0, user_id.0, role_id.0
+            ))?
+            .audit_log_reason(reason)
+            .send_empty()
+            .await
+    }
+
+    /// https://discord.com/developers/docs/resources/guild#remove-guild-member-role
+    pub async fn remove_guild_member_role(
+        &self,
+        guild_id: &GuildId,
+        user_id: &UserId,
+        role_id: &RoleId,
+        reason: Option<&str>,
+    ) -> Result<()> {
+        self.http_client
+            .delete(&format!(
+                "/guilds/{}/members/{}/roles/{}",
+                guild_id.0, user_id.0, role_id.0
+            ))?
+            .audit_log_reason(reason)
+            .send_empty()
+            .await
+    }
+
+    /// Kicks a member from the guild.
+    ///
+    /// https://discord.com/developers/docs/resources/guild#remove-guild-member
+    pub async fn remove_guild_member(
+        &self,
+        guild_id: &GuildId,
+        user_id: &UserId,
+        reason: Option<&str>,
+    ) -> Result<()> {
+        self.http_client
+            .delete(&format!("/guilds/{}/members/{}", guild_id.0, user_id.0))?
+            .audit_log_reason(reason)
+            .send_empty()
+            .await
+    }
+}
+
+pub mod requests {
+    #[cfg(feature = "debug")]
+    use std::fmt;
+
+    use serde::{Serialize, Serializer};
+    use time::OffsetDateTime;
+
+    use crate::{
+        api::objects::{
+            channel::ChannelId, guild::GuildMemberFlags, permissions::RoleId, user::UserId,
+        },
+        timestamp::rfc3339,
+    };
+
+    /// Discord docs: https://discord.com/developers/docs/resources/guild#list-guild-members-query-string-params
+    #[derive(Default)]
+    #[cfg_attr(feature = "clone", derive(Clone))]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    #[derive(Serialize)]
+    pub struct ListGuildMembers {
+        /// 1-1000, defaults to 1.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub limit: Option<u16>,
+        /// Returns members with a greater user ID, for paging through members.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub after: Option<UserId>,
+    }
+
+    /// Discord docs: https://discord.com/developers/docs/resources/guild#search-guild-members-query-string-params
+    #[derive(Default)]
+    #[cfg_attr(feature = "clone", derive(Clone))]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    #[derive(Serialize)]
+    pub struct SearchGuildMembers {
+        /// Prefix of the username or nickname to match.
+        pub query: String,
+        /// 1-1000, defaults to 1.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub limit: Option<u16>,
+    }
+
+    /// `nick`, `roles`, `mute`, and `deaf` need the matching permissions.
+    ///
+    /// Discord docs: https://discord.com/developers/docs/resources/guild#add-guild-member-json-params
+    #[derive(Default)]
+    #[cfg_attr(feature = "clone", derive(Clone))]
+    #[derive(Serialize)]
+    pub struct AddGuildMember {
+        /// OAuth2 access token with the `guilds.join` scope, granted to the bot's application.
+        pub access_token: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub nick: Option<String>,
+        /// Replaces the roles the user would otherwise get, such as from membership screening.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub roles: Option<Vec<RoleId>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub mute: Option<bool>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub deaf: Option<bool>,
+    }
+
+    // The access token is left out so it doesn't end up in logs.
+    #[cfg(feature = "debug")]
+    impl fmt::Debug for AddGuildMember {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("AddGuildMember")
+                .field("nick", &self.nick)
+                .field("roles", &self.roles)
+                .field("mute", &self.mute)
+                .field("deaf", &self.deaf)
+                .finish_non_exhaustive()
+        }
+    }
+
+    /// Fields set to `None` are left as is, and fields with an `Option<Option<_>>` are cleared
+    /// when set to `Some(None)`.
+    ///
+    /// Discord docs: https://discord.com/developers/docs/resources/guild#modify-guild-member-json-params
+    #[derive(Default)]
+    #[cfg_attr(feature = "clone", derive(Clone))]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    #[derive(Serialize)]
+    pub struct ModifyGuildMember {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub nick: Option<Option<String>>,
+        /// Replaces all of the member's roles.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub roles: Option<Vec<RoleId>>,
+        /// Only for members connected to voice.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub mute: Option<bool>,
+        /// Only for members connected to voice.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub deaf: Option<bool>,
+        /// Voice channel to move the member to, or `Some(None)` to disconnect them.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub channel_id: Option<Option<ChannelId>>,
+        /// Times the member out until the given time, up to 28 days in the future,
+        /// or removes the timeout when set to `Some(None)`.
+        #[serde(skip_serializing_if = "Option::is_none", serialize_with = "timeout")]
+        pub communication_disabled_until: Option<Option<OffsetDateTime>>,
+        /// Only `BYPASSES_VERIFICATION` can be set.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub flags: Option<GuildMemberFlags>,
+    }
+
+    fn timeout<S: Serializer>(
+        datetime: &Option<Option<OffsetDateTime>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        rfc3339::option::serialize(&datetime.flatten(), serializer)
+    }
+}
+
+#[test]
+fn test_modify_guild_member() {
+    use time::OffsetDateTime;
+
+    let member = requests::ModifyGuildMember {
+        nick: Some(None),
+        communication_disabled_until: Some(Some(
+            OffsetDateTime::from_unix_timestamp(1717245000).unwrap(),
+        )),
+        ..Default::default()
+    };
+    assert_eq!(
+        r#"{"nick":null,"communication_disabled_until":"2024-06-01T12:30:00Z"}"#,
+        serde_json::to_string(&member).unwrap()
+    );
+
+    let member = requests::ModifyGuildMember {
+        communication_disabled_until: Some(None),
+        ..Default::default()
+    };
+    assert_eq!(
+        r#"{"communication_disabled_until":null}"#,
+        serde_json::to_string(&member).unwrap()
+    );
+}
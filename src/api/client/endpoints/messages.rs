@@ -0,0 +1,335 @@
+use std::sync::Arc;
+
+use serde::Serialize;
+
+use crate::api::{
+    client::{http_client::HttpClient, AttachmentFile, Result},
+    objects::channel::{ChannelId, Message, MessageId},
+};
+
+#[derive(Clone)]
+pub struct Messages {
+    http_client: Arc<HttpClient>,
+}
+
+impl Messages {
+    pub fn new(http_client: Arc<HttpClient>) -> Self {
+        Messages { http_client }
+    }
+
+    /// https://discord.com/developers/docs/resources/channel#get-channel-messages
+    pub async fn get_channel_messages(
+        &self,
+        channel_id: &ChannelId,
+        query: &requests::GetChannelMessages,
+    ) -> Result<Vec<Message>> {
+        self.http_client
+            .get(&format!("/channels/{}/messages", channel_id.0))?
+            .query(query)
+            .send_json()
+            .await
+    }
+
+    /// https://discord.com/developers/docs/resources/channel#get-channel-message
+    pub async fn get_channel_message(
+        &self,
+        channel_id: &ChannelId,
+        message_id: &MessageId,
+    ) -> Result<Message> {
+        self.http_client
+            .get(&format!(
+                "/channels/{}/messages/{}",
+                channel_id.0, message_id.0
+            ))?
+            .send_json()
+            .await
+    }
+
+    /// Sends a message, uploading `files` as new attachments.
+    ///
+    /// https://discord.com/developers/docs/resources/channel#create-message
+    pub async fn create_message(
+        &self,
+        channel_id: &ChannelId,
+        message: &requests::CreateMessage,
+        files: Vec<AttachmentFile>,
+    ) -> Result<Message> {
+        self.http_client
+            .post(&format!("/channels/{}/messages", channel_id.0))?
+            .json_with_files(message, files)?
+            .send_json()
+            .await
+    }
+
+    /// Publishes a message in an announcement channel to the channels following it.
+    ///
+    /// https://discord.com/developers/docs/resources/channel#crosspost-message
+    pub async fn crosspost_message(
+        &self,
+        channel_id: &ChannelId,
+        message_id: &MessageId,
+    ) -> Result<Message> {
+        self.http_client
+            .post(&format!(
+                "/channels/{}/messages/{}/crosspost",
+                channel_id.0, message_id.0
+            ))?
+            .send_json()
+            .await
+    }
+
+    /// Edits a message, uploading `files` as new attachments.
+    ///
+    /// https://discord.com/developers/docs/resources/channel#edit-message
+    pub async fn edit_message(
+        &self,
+        channel_id: &ChannelId,
+        message_id: &MessageId,
+        message: &requests::EditMessage,
+        files: Vec<AttachmentFile>,
+    ) -> Result<Message> {
+        self.http_client
+            .patch(&format!(
+                "/channels/{}/messages/{}",
+                channel_id.0, message_id.0
+            ))?
+            .json_with_files(message, files)?
+            .send_json()
+            .await
+    }
+
+    /// https://discord.com/developers/docs/resources/channel#delete-message
+    pub async fn delete_message(
+        &self,
+        channel_id: &ChannelId,
+        message_id: &MessageId,
+        reason: Option<&str>,
+    ) -> Result<()> {
+        self.http_client
+            .delete(&format!(
+                "/channels/{}/messages/{}",
+                channel_id.0, message_id.0
+            ))?
+            .audit_log_reason(reason)
+            .send_empty()
+            .await
+    }
+
+    /// Deletes 2-100 messages, none of which can be older than 2 weeks.
+    ///
+    /// https://discord.com/developers/docs/resources/channel#bulk-delete-messages
+    pub async fn bulk_delete_messages(
+        &self,
+        channel_id: &ChannelId,
+        messages: &[MessageId],
+        reason: Option<&str>,
+    ) -> Result<()> {
+        #[derive(Serialize)]
+        struct Body<'a> {
+            messages: &'a [MessageId],
+        }
+
+        self.http_client
+            .post(&format!("/channels/{}/messages/bulk-delete", channel_id.0))?
+            .json(&Body { messages })
+            .audit_log_reason(reason)
+            .send_empty()
+            .await
+    }
+
+    /// https://discord.com/developers/docs/resources/channel#get-pinned-messages
+    pub async fn get_pinned_messages(&self, channel_id: &ChannelId) -> Result<Vec<Message>> {
+        self.http_client
+            .get(&format!("/channels/{}/pins", channel_id.0))?
+            .send_json()
+            .await
+    }
+
+    /// Channels can have up to 50 pinned messages.
+    ///
+    /// https://discord.com/developers/docs/resources/channel#pin-message
+    pub async fn pin_message(
+        &self,
+        channel_id: &ChannelId,
+        message_id: &MessageId,
+        reason: Option<&str>,
+    ) -> Result<()> {
+        self.http_client
+            .put(&format!("/channels/{}/pins/{}", channel_id.0, message_id.0))?
+            .audit_log_reason(reason)
+            .send_empty()
+            .await
+    }
+
+    /// https://discord.com/developers/docs/resources/channel#unpin-message
+    pub async fn unpin_message(
+        &self,
+        channel_id: &ChannelId,
+        message_id: &MessageId,
+        reason: Option<&str>,
+    ) -> Result<()> {
+        self.http_client
+            .delete(&format!("/channels/{}/pins/{}", channel_id.0, message_id.0))?
+            .audit_log_reason(reason)
+            .send_empty()
+            .await
+    }
+}
+
+pub mod requests {
+    use serde::Serialize;
+
+    use crate::api::objects::{
+        channel::{
+            AllowedMentions, Attachment, AttachmentId, Embed, MessageFlags, MessageId,
+            MessageReference, Nonce,
+        },
+        message_components::Component,
+        poll::PollCreateRequest,
+        sticker::StickerId,
+    };
+
+    /// Discord docs: https://discord.com/developers/docs/resources/channel#get-channel-messages-query-string-params
+    #[derive(Default)]
+    #[cfg_attr(feature = "clone", derive(Clone))]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    #[derive(Serialize)]
+    pub struct GetChannelMessages {
+        /// Defaults to the most recent messages.
+        #[serde(flatten, skip_serializing_if = "Option::is_none")]
+        pub position: Option<MessagePosition>,
+        /// 1-100, defaults to 50.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub limit: Option<u8>,
+    }
+
+    #[cfg_attr(feature = "clone", derive(Clone))]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    #[derive(Serialize)]
+    #[serde(rename_all = "lowercase")]
+    pub enum MessagePosition {
+        Around(MessageId),
+        Before(MessageId),
+        After(MessageId),
+    }
+
+    /// At least one of `content`, `embeds`, `sticker_ids`, `components`, `poll`,
+    /// or a file is required.
+    ///
+    /// Discord docs: https://discord.com/developers/docs/resources/channel#create-message-jsonform-params
+    #[derive(Default)]
+    #[cfg_attr(feature = "clone", derive(Clone))]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    #[derive(Serialize)]
+    pub struct CreateMessage {
+        /// Up to 2000 characters.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub content: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub nonce: Option<Nonce>,
+        /// Whether to return the message that was already sent with the same `nonce`
+        /// (within the past few minutes) rather than sending it again.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub enforce_nonce: Option<bool>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub tts: Option<bool>,
+        /// Up to 10 embeds.
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        pub embeds: Vec<Embed>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub allowed_mentions: Option<AllowedMentions>,
+        /// Replies to (or forwards) the referenced message.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub message_reference: Option<MessageReference>,
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        pub components: Vec<Component>,
+        /// Up to 3 stickers.
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        pub sticker_ids: Vec<StickerId>,
+        /// Only `SUPPRESS_EMBEDS` and `SUPPRESS_NOTIFICATIONS` can be set.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub flags: Option<MessageFlags>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub poll: Option<PollCreateRequest>,
+    }
+
+    /// Fields set to `None` are left as is, and fields with an `Option<Option<_>>` are cleared
+    /// when set to `Some(None)`.
+    ///
+    /// Discord docs: https://discord.com/developers/docs/resources/channel#edit-message-jsonform-params
+    #[derive(Default)]
+    #[cfg_attr(feature = "clone", derive(Clone))]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    #[derive(Serialize)]
+    pub struct EditMessage {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub content: Option<Option<String>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub embeds: Option<Vec<Embed>>,
+        /// Only `SUPPRESS_EMBEDS` can be set.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub flags: Option<MessageFlags>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub allowed_mentions: Option<Option<AllowedMentions>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub components: Option<Vec<Component>>,
+        /// Existing attachments to keep, with the rest removed.
+        /// Must be set when uploading files, or existing attachments will be removed.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub attachments: Option<Vec<PartialAttachment>>,
+    }
+
+    /// Existing attachment to keep when editing a message.
+    #[cfg_attr(feature = "clone", derive(Clone))]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    #[derive(Serialize)]
+    pub struct PartialAttachment {
+        pub id: AttachmentId,
+    }
+
+    impl From<Attachment> for PartialAttachment {
+        fn from(value: Attachment) -> Self {
+            PartialAttachment { id: value.id }
+        }
+    }
+}
+
+#[test]
+fn test_create_message() {
+    use crate::api::objects::channel::{AllowedMentions, Embed, MessageReference};
+
+    let message = requests::CreateMessage {
+        content: Some(String::from("Pong!")),
+        embeds: vec![Embed {
+            title: Some(String::from("Latency")),
+            color: Some(0x5865F2),
+            ..Default::default()
+        }],
+        allowed_mentions: Some(AllowedMentions::default()),
+        message_reference: Some(MessageReference::reply(MessageId(String::from("1234")))),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        r#"{"content":"Pong!","embeds":[{"title":"Latency","color":5793266}],"allowed_mentions":{"parse":[],"roles":[],"users":[],"replied_user":false},"message_reference":{"message_id":"1234"}}"#,
+        serde_json::to_string(&message).unwrap()
+    );
+}
+
+#[test]
+fn test_get_channel_messages() {
+    let url = reqwest::Client::new()
+        .get("https://discord.com/api/v10/channels/1/messages")
+        .query(&requests::GetChannelMessages {
+            position: Some(requests::MessagePosition::Before(MessageId(String::from(
+                "1234",
+            )))),
+            limit: Some(10),
+        })
+        .build()
+        .unwrap()
+        .url()
+        .clone();
+
+    assert_eq!(Some("before=1234&limit=10"), url.query());
+}
@@ -1 +1,14 @@
+pub mod application_commands;
+pub mod audit_logs;
+pub mod auto_moderation;
+pub mod bans;
+pub mod channels;
 pub mod gateway;
+pub mod guilds;
+pub mod interactions;
+pub mod members;
+pub mod messages;
+pub mod reactions;
+pub mod roles;
+pub mod stickers;
+pub mod webhooks;
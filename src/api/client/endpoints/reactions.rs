@@ -0,0 +1,253 @@
+use std::sync::Arc;
+
+use crate::api::{
+    client::{http_client::HttpClient, Result},
+    objects::{
+        channel::{ChannelId, MessageId},
+        user::{User, UserId},
+    },
+};
+
+use self::requests::RequestReactionType;
+
+#[derive(Clone)]
+pub struct Reactions {
+    http_client: Arc<HttpClient>,
+}
+
+impl Reactions {
+    pub fn new(http_client: Arc<HttpClient>) -> Self {
+        Reactions { http_client }
+    }
+
+    /// https://discord.com/developers/docs/resources/channel#create-reaction
+    pub async fn create_reaction(
+        &self,
+        channel_id: &ChannelId,
+        message_id: &MessageId,
+        emoji: &RequestReactionType,
+    ) -> Result<()> {
+        self.http_client
+            .put(&format!(
+                "{}/@me",
+                reactions_path(channel_id, message_id, emoji)
+            ))?
+            .send_empty()
+            .await
+    }
+
+    /// https://discord.com/developers/docs/resources/channel#delete-own-reaction
+    pub async fn delete_own_reaction(
+        &self,
+        channel_id: &ChannelId,
+        message_id: &MessageId,
+        emoji: &RequestReactionType,
+    ) -> Result<()> {
+        self.http_client
+            .delete(&format!(
+                "{}/@me",
+                reactions_path(channel_id, message_id, emoji)
+            ))?
+            .send_empty()
+            .await
+    }
+
+    /// https://discord.com/developers/docs/resources/channel#delete-user-reaction
+    pub async fn delete_user_reaction(
+        &self,
+        channel_id: &ChannelId,
+        message_id: &MessageId,
+        emoji: &RequestReactionType,
+        user_id: &UserId,
+    ) -> Result<()> {
+        self.http_client
+            .delete(&format!(
+                "{}/{}",
+                reactions_path(channel_id, message_id, emoji),
+                user_id.0
+            ))?
+            .send_empty()
+            .await
+    }
+
+    /// Users who reacted with the emoji, ordered by user ID.
+    ///
+    /// https://discord.com/developers/docs/resources/channel#get-reactions
+    pub async fn get_reactions(
+        &self,
+        channel_id: &ChannelId,
+        message_id: &MessageId,
+        emoji: &RequestReactionType,
+        query: &requests::GetReactions,
+    ) -> Result<Vec<User>> {
+        self.http_client
+            .get(&reactions_path(channel_id, message_id, emoji))?
+            .query(query)
+            .send_json()
+            .await
+    }
+
+    /// https://discord.com/developers/docs/resources/channel#delete-all-reactions
+    pub async fn delete_all_reactions(
+        &self,
+        channel_id: &ChannelId,
+        message_id: &MessageId,
+    ) -> Result<()> {
+        self.http_client
+            .delete(&format!(
+                "/channels/{}/messages/{}/reactions",
+                channel_id.0, message_id.0
+            ))?
+            .send_empty()
+            .await
+    }
+
+    /// https://discord.com/developers/docs/resources/channel#delete-all-reactions-for-emoji
+    pub async fn delete_all_reactions_for_emoji(
+        &self,
+        channel_id: &ChannelId,
+        message_id: &MessageId,
+        emoji: &RequestReactionType,
+    ) -> Result<()> {
+        self.http_client
+            .delete(&reactions_path(channel_id, message_id, emoji))?
+            .send_empty()
+            .await
+    }
+}
+
+fn reactions_path(
+    channel_id: &ChannelId,
+    message_id: &MessageId,
+    emoji: &RequestReactionType,
+) -> String {
+    format!(
+        "/channels/{}/messages/{}/reactions/{}",
+        channel_id.0,
+        message_id.0,
+        emoji.path_segment()
+    )
+}
+
+pub mod requests {
+    use percent_encoding::utf8_percent_encode;
+    use serde::Serialize;
+    use serde_repr::Serialize_repr;
+
+    use crate::api::{
+        client::{http_client::UNRESERVED, ApiError},
+        objects::{
+            emoji::{Emoji, EmojiId},
+            user::UserId,
+        },
+    };
+
+    /// Emoji to react with, which Discord expects in the path as `name:id` for custom emoji
+    /// or the unicode emoji itself.
+    #[cfg_attr(feature = "clone", derive(Clone))]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    pub enum RequestReactionType {
+        /// Discord only checks the ID, so the name can be left out.
+        Custom {
+            id: EmojiId,
+            name: Option<String>,
+        },
+        Unicode(String),
+    }
+
+    impl RequestReactionType {
+        /// Percent-encoded segment for the reaction's path.
+        pub fn path_segment(&self) -> String {
+            match self {
+                RequestReactionType::Custom { id, name } => format!(
+                    "{}:{}",
+                    utf8_percent_encode(name.as_deref().unwrap_or("_"), UNRESERVED),
+                    utf8_percent_encode(&id.0, UNRESERVED)
+                ),
+                RequestReactionType::Unicode(emoji) => {
+                    utf8_percent_encode(emoji, UNRESERVED).to_string()
+                }
+            }
+        }
+    }
+
+    impl TryFrom<&Emoji> for RequestReactionType {
+        type Error = ApiError;
+
+        fn try_from(emoji: &Emoji) -> Result<Self, Self::Error> {
+            match (&emoji.id, &emoji.name) {
+                (Some(id), name) => Ok(RequestReactionType::Custom {
+                    id: EmojiId(id.0.clone()),
+                    name: name.clone(),
+                }),
+                (None, Some(name)) => Ok(RequestReactionType::Unicode(name.clone())),
+                (None, None) => Err(ApiError::InvalidReactionEmoji),
+            }
+        }
+    }
+
+    /// Discord docs: https://discord.com/developers/docs/resources/channel#get-reactions-query-string-params
+    #[derive(Default)]
+    #[cfg_attr(feature = "clone", derive(Clone))]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    #[derive(Serialize)]
+    pub struct GetReactions {
+        /// Defaults to `Normal`.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub r#type: Option<ReactionType>,
+        /// Returns users with a greater ID, for paging through reactions.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub after: Option<UserId>,
+        /// 1-100, defaults to 25.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub limit: Option<u8>,
+    }
+
+    /// Discord docs: https://discord.com/developers/docs/resources/channel#get-reactions-reaction-types
+    #[cfg_attr(feature = "clone", derive(Clone))]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    #[derive(Serialize_repr)]
+    #[repr(u8)]
+    pub enum ReactionType {
+        Normal = 0,
+        /// Super reactions.
+        Burst = 1,
+    }
+}
+
+#[test]
+fn test_reaction_path_segment() {
+    use crate::api::objects::emoji::{Emoji, EmojiId};
+
+    let emoji = Emoji {
+        id: Some(EmojiId(String::from("1234"))),
+        name: Some(String::from("ferris_wave")),
+        roles: vec![],
+        user: None,
+        require_colons: None,
+        managed: None,
+        animated: None,
+        available: None,
+    };
+    assert_eq!(
+        "/channels/1/messages/2/reactions/ferris_wave:1234",
+        reactions_path(
+            &ChannelId(String::from("1")),
+            &MessageId(String::from("2")),
+            &RequestReactionType::try_from(&emoji).unwrap()
+        )
+    );
+
+    assert_eq!(
+        "%F0%9F%91%8D%F0%9F%8F%BD",
+        RequestReactionType::Unicode(String::from("👍🏽")).path_segment()
+    );
+    assert_eq!(
+        "_:1234",
+        RequestReactionType::Custom {
+            id: EmojiId(String::from("1234")),
+            name: None
+        }
+        .path_segment()
+    );
+}
@@ -0,0 +1,208 @@
+use std::{collections::HashMap, sync::Arc};
+
+use crate::api::{
+    client::{http_client::HttpClient, Result},
+    objects::{
+        guild::GuildId,
+        permissions::{Role, RoleId},
+    },
+};
+
+#[derive(Clone)]
+pub struct Roles {
+    http_client: Arc<HttpClient>,
+}
+
+impl Roles {
+    pub fn new(http_client: Arc<HttpClient>) -> Self {
+        Roles { http_client }
+    }
+
+    /// https://discord.com/developers/docs/resources/guild#get-guild-roles
+    pub async fn get_guild_roles(&self, guild_id: &GuildId) -> Result<Vec<Role>> {
+        self.http_client
+            .get(&format!("/guilds/{}/roles", guild_id.0))?
+            .send_json()
+            .await
+    }
+
+    /// https://discord.com/developers/docs/resources/guild#get-guild-role
+    pub async fn get_guild_role(&self, guild_id: &GuildId, role_id: &RoleId) -> Result<Role> {
+        self.http_client
+            .get(&format!("/guilds/{}/roles/{}", guild_id.0, role_id.0))?
+            .send_json()
+            .await
+    }
+
+    /// Number of members with each role, keyed by role ID. `@everyone` is left out.
+    ///
+    /// https://discord.com/developers/docs/resources/guild#get-guild-role-member-counts
+    pub async fn get_guild_role_member_counts(
+        &self,
+        guild_id: &GuildId,
+    ) -> Result<HashMap<String, u32>> {
+        self.http_client
+            .get(&format!("/guilds/{}/roles/member-counts", guild_id.0))?
+            .send_json()
+            .await
+    }
+
+    /// https://discord.com/developers/docs/resources/guild#create-guild-role
+    pub async fn create_guild_role(
+        &self,
+        guild_id: &GuildId,
+        role: &requests::CreateGuildRole,
+        reason: Option<&str>,
+    ) -> Result<Role> {
+        self.http_client
+            .post(&format!("/guilds/{}/roles", guild_id.0))?
+            .json(role)
+            .audit_log_reason(reason)
+            .send_json()
+            .await
+    }
+
+    /// Returns every role in the guild, in their new order.
+    ///
+    /// https://discord.com/developers/docs/resources/guild#modify-guild-role-positions
+    pub async fn modify_guild_role_positions(
+        &self,
+        guild_id: &GuildId,
+        positions: &[requests::ModifyGuildRolePosition],
+        reason: Option<&str>,
+    ) -> Result<Vec<Role>> {
+        self.http_client
+            .patch(&format!("/guilds/{}/roles", guild_id.0))?
+            .json(positions)
+            .audit_log_reason(reason)
+            .send_json()
+            .await
+    }
+
+    /// https://discord.com/developers/docs/resources/guild#modify-guild-role
+    pub async fn modify_guild_role(
+        &self,
+        guild_id: &GuildId,
+        role_id: &RoleId,
+        role: &requests::ModifyGuildRole,
+        reason: Option<&str>,
+    ) -> Result<Role> {
+        self.http_client
+            .patch(&format!("/guilds/{}/roles/{}", guild_id.0, role_id.0))?
+            .json(role)
+            .audit_log_reason(reason)
+            .send_json()
+            .await
+    }
+
+    /// https://discord.com/developers/docs/resources/guild#delete-guild-role
+    pub async fn delete_guild_role(
+        &self,
+        guild_id: &GuildId,
+        role_id: &RoleId,
+        reason: Option<&str>,
+    ) -> Result<()> {
+        self.http_client
+            .delete(&format!("/guilds/{}/roles/{}", guild_id.0, role_id.0))?
+            .audit_log_reason(reason)
+            .send_empty()
+            .await
+    }
+}
+
+pub mod requests {
+    use serde::{Serialize, Serializer};
+
+    use crate::api::{
+        client::ImageData,
+        objects::permissions::{rgb, Permissions, Rgb, RoleId},
+    };
+
+    /// Discord docs: https://discord.com/developers/docs/resources/guild#create-guild-role-json-params
+    #[derive(Default)]
+    #[cfg_attr(feature = "clone", derive(Clone))]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    #[derive(Serialize)]
+    pub struct CreateGuildRole {
+        /// Defaults to `new role`.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub name: Option<String>,
+        /// Defaults to the permissions of `@everyone`.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub permissions: Option<Permissions>,
+        #[serde(
+            skip_serializing_if = "Option::is_none",
+            serialize_with = "rgb::option::serialize"
+        )]
+        pub color: Option<Rgb>,
+        /// Whether to show members with the role separately in the member list.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub hoist: Option<bool>,
+        /// Needs the `ROLE_ICONS` feature. Can't be used with `unicode_emoji`.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub icon: Option<ImageData>,
+        /// Needs the `ROLE_ICONS` feature. Can't be used with `icon`.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub unicode_emoji: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub mentionable: Option<bool>,
+    }
+
+    /// Discord docs: https://discord.com/developers/docs/resources/guild#modify-guild-role-positions-json-params
+    #[cfg_attr(feature = "clone", derive(Clone))]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    #[derive(Serialize)]
+    pub struct ModifyGuildRolePosition {
+        pub id: RoleId,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub position: Option<Option<i32>>,
+    }
+
+    /// Fields set to `None` are left as is, and fields with an `Option<Option<_>>` are cleared
+    /// when set to `Some(None)`.
+    ///
+    /// Discord docs: https://discord.com/developers/docs/resources/guild#modify-guild-role-json-params
+    #[derive(Default)]
+    #[cfg_attr(feature = "clone", derive(Clone))]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    #[derive(Serialize)]
+    pub struct ModifyGuildRole {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub name: Option<Option<String>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub permissions: Option<Option<Permissions>>,
+        #[serde(skip_serializing_if = "Option::is_none", serialize_with = "color")]
+        pub color: Option<Option<Rgb>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub hoist: Option<Option<bool>>,
+        /// Needs the `ROLE_ICONS` feature.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub icon: Option<Option<ImageData>>,
+        /// Needs the `ROLE_ICONS` feature.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub unicode_emoji: Option<Option<String>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub mentionable: Option<Option<bool>>,
+    }
+
+    fn color<S: Serializer>(color: &Option<Option<Rgb>>, serializer: S) -> Result<S::Ok, S::Error> {
+        rgb::option::serialize(color.as_ref().unwrap_or(&None), serializer)
+    }
+}
+
+#[test]
+fn test_create_guild_role() {
+    use crate::api::{client::ImageData, objects::permissions::Rgb};
+
+    let role = requests::CreateGuildRole {
+        name: Some(String::from("Ferris")),
+        color: Some(Rgb(0xF7, 0x4C, 0x00)),
+        icon: Some(ImageData::new("image/png", b"png")),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        r#"{"name":"Ferris","color":16206848,"icon":"data:image/png;base64,cG5n"}"#,
+        serde_json::to_string(&role).unwrap()
+    );
+}
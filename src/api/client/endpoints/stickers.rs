@@ -0,0 +1,55 @@
+use std::sync::Arc;
+
+use reqwest::multipart::Form;
+
+use crate::api::{
+    client::{http_client::HttpClient, Result},
+    objects::{guild::GuildId, sticker::Sticker},
+};
+
+#[derive(Clone)]
+pub struct Stickers {
+    http_client: Arc<HttpClient>,
+}
+
+impl Stickers {
+    pub fn new(http_client: Arc<HttpClient>) -> Self {
+        Stickers { http_client }
+    }
+
+    /// https://discord.com/developers/docs/resources/sticker#create-guild-sticker
+    pub async fn create_guild_sticker(
+        &self,
+        guild_id: &GuildId,
+        sticker: requests::CreateGuildSticker,
+        reason: Option<&str>,
+    ) -> Result<Sticker> {
+        let form = Form::new()
+            .text("name", sticker.name)
+            .text("description", sticker.description)
+            .text("tags", sticker.tags)
+            .part("file", sticker.file.into_part());
+        self.http_client
+            .post(&format!("/guilds/{}/stickers", guild_id.0))?
+            .form(form)
+            .audit_log_reason(reason)
+            .send_json()
+            .await
+    }
+}
+
+pub mod requests {
+    use crate::api::client::AttachmentFile;
+
+    /// Discord docs: https://discord.com/developers/docs/resources/sticker#create-guild-sticker-form-params
+    pub struct CreateGuildSticker {
+        /// 2-30 characters.
+        pub name: String,
+        /// Empty or 2-100 characters.
+        pub description: String,
+        /// Autocomplete/suggestion tags for the sticker, such as the name of a related unicode emoji.
+        pub tags: String,
+        /// PNG, APNG, GIF, or Lottie JSON file, max 512 KiB.
+        pub file: AttachmentFile,
+    }
+}
@@ -0,0 +1,489 @@
+use std::sync::Arc;
+
+use reqwest::StatusCode;
+use serde::Serialize;
+
+use crate::api::{
+    client::{http_client::HttpClient, ApiError, AttachmentFile, Result},
+    objects::{
+        channel::{ChannelId, Message, MessageId},
+        guild::GuildId,
+        webhook::{Webhook, WebhookId},
+    },
+};
+
+/// Endpoints ending in `_with_token` are authenticated by the webhook's token,
+/// so they work without a bot token (see `WebhookClient`).
+#[derive(Clone)]
+pub struct Webhooks {
+    http_client: Arc<HttpClient>,
+}
+
+impl Webhooks {
+    pub fn new(http_client: Arc<HttpClient>) -> Self {
+        Webhooks { http_client }
+    }
+
+    /// https://discord.com/developers/docs/resources/webhook#create-webhook
+    pub async fn create_webhook(
+        &self,
+        channel_id: &ChannelId,
+        webhook: &requests::CreateWebhook,
+        reason: Option<&str>,
+    ) -> Result<Webhook> {
+        self.http_client
+            .post(&format!("/channels/{}/webhooks", channel_id.0))?
+            .json(webhook)
+            .audit_log_reason(reason)
+            .send_json()
+            .await
+    }
+
+    /// https://discord.com/developers/docs/resources/webhook#get-channel-webhooks
+    pub async fn get_channel_webhooks(&self, channel_id: &ChannelId) -> Result<Vec<Webhook>> {
+        self.http_client
+            .get(&format!("/channels/{}/webhooks", channel_id.0))?
+            .send_json()
+            .await
+    }
+
+    /// https://discord.com/developers/docs/resources/webhook#get-guild-webhooks
+    pub async fn get_guild_webhooks(&self, guild_id: &GuildId) -> Result<Vec<Webhook>> {
+        self.http_client
+            .get(&format!("/guilds/{}/webhooks", guild_id.0))?
+            .send_json()
+            .await
+    }
+
+    /// https://discord.com/developers/docs/resources/webhook#get-webhook
+    pub async fn get_webhook(&self, webhook_id: &WebhookId) -> Result<Webhook> {
+        self.http_client
+            .get(&format!("/webhooks/{}", webhook_id.0))?
+            .send_json()
+            .await
+    }
+
+    /// https://discord.com/developers/docs/resources/webhook#get-webhook-with-token
+    pub async fn get_webhook_with_token(
+        &self,
+        webhook_id: &WebhookId,
+        webhook_token: &str,
+    ) -> Result<Webhook> {
+        self.http_client
+            .get(&format!("/webhooks/{}/{}", webhook_id.0, webhook_token))?
+            .send_json()
+            .await
+    }
+
+    /// https://discord.com/developers/docs/resources/webhook#modify-webhook
+    pub async fn modify_webhook(
+        &self,
+        webhook_id: &WebhookId,
+        webhook: &requests::ModifyWebhook,
+        reason: Option<&str>,
+    ) -> Result<Webhook> {
+        self.http_client
+            .patch(&format!("/webhooks/{}", webhook_id.0))?
+            .json(webhook)
+            .audit_log_reason(reason)
+            .send_json()
+            .await
+    }
+
+    /// Same as `modify_webhook`, except `channel_id` can't be changed.
+    ///
+    /// https://discord.com/developers/docs/resources/webhook#modify-webhook-with-token
+    pub async fn modify_webhook_with_token(
+        &self,
+        webhook_id: &WebhookId,
+        webhook_token: &str,
+        webhook: &requests::ModifyWebhook,
+        reason: Option<&str>,
+    ) -> Result<Webhook> {
+        self.http_client
+            .patch(&format!("/webhooks/{}/{}", webhook_id.0, webhook_token))?
+            .json(webhook)
+            .audit_log_reason(reason)
+            .send_json()
+            .await
+    }
+
+    /// https://discord.com/developers/docs/resources/webhook#delete-webhook
+    pub async fn delete_webhook(&self, webhook_id: &WebhookId, reason: Option<&str>) -> Result<()> {
+        self.http_client
+            .delete(&format!("/webhooks/{}", webhook_id.0))?
+            .audit_log_reason(reason)
+            .send_empty()
+            .await
+    }
+
+    /// https://discord.com/developers/docs/resources/webhook#delete-webhook-with-token
+    pub async fn delete_webhook_with_token(
+        &self,
+        webhook_id: &WebhookId,
+        webhook_token: &str,
+        reason: Option<&str>,
+    ) -> Result<()> {
+        self.http_client
+            .delete(&format!("/webhooks/{}/{}", webhook_id.0, webhook_token))?
+            .audit_log_reason(reason)
+            .send_empty()
+            .await
+    }
+
+    /// Sends a message, uploading `files` as attachments.
+    /// Returns the message only when `wait` is set in `query`.
+    ///
+    /// https://discord.com/developers/docs/resources/webhook#execute-webhook
+    pub async fn execute_webhook(
+        &self,
+        webhook_id: &WebhookId,
+        webhook_token: &str,
+        message: &requests::ExecuteWebhook,
+        query: &requests::ExecuteWebhookQuery,
+        files: Vec<AttachmentFile>,
+    ) -> Result<Option<Message>> {
+        let response = self
+            .http_client
+            .post(&format!("/webhooks/{}/{}", webhook_id.0, webhook_token))?
+            .query(query)
+            .json_with_files(message, files)?
+            .send()
+            .await?;
+        if response.status() == StatusCode::NO_CONTENT {
+            return Ok(None);
+        }
+        if !response.status().is_success() {
+            return Err(ApiError::from_response(response).await);
+        }
+
+        Ok(Some(response.json().await?))
+    }
+
+    /// Sends a message from a body in Slack's incoming webhook format.
+    ///
+    /// https://discord.com/developers/docs/resources/webhook#execute-slackcompatible-webhook
+    pub async fn execute_slack_compatible_webhook<B: Serialize + ?Sized>(
+        &self,
+        webhook_id: &WebhookId,
+        webhook_token: &str,
+        body: &B,
+        query: &requests::ExecuteCompatibleWebhookQuery,
+    ) -> Result<()> {
+        self.http_client
+            .post(&format!(
+                "/webhooks/{}/{}/slack",
+                webhook_id.0, webhook_token
+            ))?
+            .query(query)
+            .json(body)
+            .send_empty()
+            .await
+    }
+
+    /// Sends a message for a GitHub webhook `event` (the `X-GitHub-Event` header),
+    /// such as `push` or `pull_request`, with GitHub's payload as the body.
+    ///
+    /// https://discord.com/developers/docs/resources/webhook#execute-githubcompatible-webhook
+    pub async fn execute_github_compatible_webhook<B: Serialize + ?Sized>(
+        &self,
+        webhook_id: &WebhookId,
+        webhook_token: &str,
+        event: &str,
+        payload: &B,
+        query: &requests::ExecuteCompatibleWebhookQuery,
+    ) -> Result<()> {
+        self.http_client
+            .post(&format!(
+                "/webhooks/{}/{}/github",
+                webhook_id.0, webhook_token
+            ))?
+            .query(query)
+            .header("x-github-event", event)?
+            .json(payload)
+            .send_empty()
+            .await
+    }
+
+    /// `thread_id` is required for messages in threads.
+    ///
+    /// https://discord.com/developers/docs/resources/webhook#get-webhook-message
+    pub async fn get_webhook_message(
+        &self,
+        webhook_id: &WebhookId,
+        webhook_token: &str,
+        message_id: &MessageId,
+        thread_id: Option<&ChannelId>,
+    ) -> Result<Message> {
+        self.http_client
+            .get(&format!(
+                "/webhooks/{}/{}/messages/{}",
+                webhook_id.0, webhook_token, message_id.0
+            ))?
+            .query(&ThreadQuery { thread_id })
+            .send_json()
+            .await
+    }
+
+    /// Edits a message sent by the webhook, uploading `files` as new attachments.
+    /// `thread_id` is required for messages in threads.
+    ///
+    /// https://discord.com/developers/docs/resources/webhook#edit-webhook-message
+    pub async fn edit_webhook_message(
+        &self,
+        webhook_id: &WebhookId,
+        webhook_token: &str,
+        message_id: &MessageId,
+        thread_id: Option<&ChannelId>,
+        message: &requests::EditWebhookMessage,
+        files: Vec<AttachmentFile>,
+    ) -> Result<Message> {
+        self.http_client
+            .patch(&format!(
+                "/webhooks/{}/{}/messages/{}",
+                webhook_id.0, webhook_token, message_id.0
+            ))?
+            .query(&ThreadQuery { thread_id })
+            .json_with_files(message, files)?
+            .send_json()
+            .await
+    }
+
+    /// `thread_id` is required for messages in threads.
+    ///
+    /// https://discord.com/developers/docs/resources/webhook#delete-webhook-message
+    pub async fn delete_webhook_message(
+        &self,
+        webhook_id: &WebhookId,
+        webhook_token: &str,
+        message_id: &MessageId,
+        thread_id: Option<&ChannelId>,
+    ) -> Result<()> {
+        self.http_client
+            .delete(&format!(
+                "/webhooks/{}/{}/messages/{}",
+                webhook_id.0, webhook_token, message_id.0
+            ))?
+            .query(&ThreadQuery { thread_id })
+            .send_empty()
+            .await
+    }
+}
+
+#[derive(Serialize)]
+struct ThreadQuery<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    thread_id: Option<&'a ChannelId>,
+}
+
+pub mod requests {
+    use serde::Serialize;
+
+    use crate::api::{
+        client::{endpoints::messages::requests::PartialAttachment, ImageData},
+        objects::{
+            channel::{AllowedMentions, ChannelId, Embed, MessageFlags},
+            message_components::Component,
+            poll::PollCreateRequest,
+        },
+    };
+
+    /// Discord docs: https://discord.com/developers/docs/resources/webhook#create-webhook-json-params
+    #[cfg_attr(feature = "clone", derive(Clone))]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    #[derive(Serialize)]
+    pub struct CreateWebhook {
+        /// 1-80 characters, and can't contain `clyde` or `discord`.
+        pub name: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub avatar: Option<ImageData>,
+    }
+
+    /// Fields set to `None` are left as is, and fields with an `Option<Option<_>>` are cleared
+    /// when set to `Some(None)`.
+    ///
+    /// Discord docs: https://discord.com/developers/docs/resources/webhook#modify-webhook-json-params
+    #[derive(Default)]
+    #[cfg_attr(feature = "clone", derive(Clone))]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    #[derive(Serialize)]
+    pub struct ModifyWebhook {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub name: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub avatar: Option<Option<ImageData>>,
+        /// Channel to move the webhook to. Can't be changed with the webhook's token.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub channel_id: Option<ChannelId>,
+    }
+
+    /// At least one of `content`, `embeds`, `components`, `poll`, or a file is required.
+    ///
+    /// Discord docs: https://discord.com/developers/docs/resources/webhook#execute-webhook-jsonform-params
+    #[derive(Default)]
+    #[cfg_attr(feature = "clone", derive(Clone))]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    #[derive(Serialize)]
+    pub struct ExecuteWebhook {
+        /// Up to 2000 characters.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub content: Option<String>,
+        /// Overrides the webhook's name.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub username: Option<String>,
+        /// Overrides the webhook's avatar.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub avatar_url: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub tts: Option<bool>,
+        /// Up to 10 embeds.
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        pub embeds: Vec<Embed>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub allowed_mentions: Option<AllowedMentions>,
+        /// Needs `with_components` for webhooks that aren't owned by an application.
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        pub components: Vec<Component>,
+        /// Only `SUPPRESS_EMBEDS` and `SUPPRESS_NOTIFICATIONS` can be set.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub flags: Option<MessageFlags>,
+        /// Creates a thread with the name, only for webhooks in forum and media channels.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub thread_name: Option<String>,
+        /// Tags for the thread created in a forum or media channel.
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        pub applied_tags: Vec<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub poll: Option<PollCreateRequest>,
+    }
+
+    /// Discord docs: https://discord.com/developers/docs/resources/webhook#execute-webhook-query-string-params
+    #[derive(Default)]
+    #[cfg_attr(feature = "clone", derive(Clone))]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    #[derive(Serialize)]
+    pub struct ExecuteWebhookQuery {
+        /// Waits for the message to be sent and returns it, rather than returning right away.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub wait: Option<bool>,
+        /// Thread in the webhook's channel to send the message to.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub thread_id: Option<ChannelId>,
+        /// Allows non-interactive components for webhooks that aren't owned by an application.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub with_components: Option<bool>,
+    }
+
+    /// Discord docs: https://discord.com/developers/docs/resources/webhook#execute-slackcompatible-webhook-query-string-params
+    #[derive(Default)]
+    #[cfg_attr(feature = "clone", derive(Clone))]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    #[derive(Serialize)]
+    pub struct ExecuteCompatibleWebhookQuery {
+        /// Thread in the webhook's channel to send the message to.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub thread_id: Option<ChannelId>,
+        /// Waits for the message to be sent before returning.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub wait: Option<bool>,
+    }
+
+    /// Fields set to `None` are left as is, and fields with an `Option<Option<_>>` are cleared
+    /// when set to `Some(None)`.
+    ///
+    /// Discord docs: https://discord.com/developers/docs/resources/webhook#edit-webhook-message-jsonform-params
+    #[derive(Default)]
+    #[cfg_attr(feature = "clone", derive(Clone))]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    #[derive(Serialize)]
+    pub struct EditWebhookMessage {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub content: Option<Option<String>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub embeds: Option<Vec<Embed>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub allowed_mentions: Option<Option<AllowedMentions>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub components: Option<Vec<Component>>,
+        /// Existing attachments to keep, with the rest removed.
+        /// Must be set when uploading files, or existing attachments will be removed.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub attachments: Option<Vec<PartialAttachment>>,
+    }
+}
+
+#[test]
+fn test_execute_webhook_query() {
+    let url = reqwest::Client::new()
+        .post("https://discord.com/api/v10/webhooks/1/token")
+        .query(&requests::ExecuteWebhookQuery {
+            wait: Some(true),
+            thread_id: Some(ChannelId(String::from("1234"))),
+            ..Default::default()
+        })
+        .build()
+        .unwrap()
+        .url()
+        .clone();
+
+    assert_eq!(Some("wait=true&thread_id=1234"), url.query());
+}
+
+#[test]
+fn test_edit_webhook_message() {
+    let message = requests::EditWebhookMessage {
+        content: Some(None),
+        embeds: Some(vec![]),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        r#"{"content":null,"embeds":[]}"#,
+        serde_json::to_string(&message).unwrap()
+    );
+}
+
+/// Sends a GitHub webhook to a stand-in API on loopback, which checks the event header.
+#[tokio::test]
+async fn test_execute_github_compatible_webhook() {
+    use serde_json::json;
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+    };
+    use url::Url;
+
+    use crate::api::client::Api;
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base_url = Url::parse(&format!("http://{}/api/", listener.local_addr().unwrap())).unwrap();
+
+    let server = tokio::spawn(async move {
+        let (mut stream, _) = listener.accept().await.unwrap();
+        let mut request = vec![0; 4096];
+        let length = stream.read(&mut request).await.unwrap();
+        let request = String::from_utf8_lossy(&request[..length]).to_string();
+        stream
+            .write_all(b"HTTP/1.1 204 No Content\r\nconnection: close\r\n\r\n")
+            .await
+            .unwrap();
+        stream.shutdown().await.unwrap();
+        request
+    });
+
+    let api = Api::builder().base_url(base_url).build().unwrap();
+    api.webhooks()
+        .execute_github_compatible_webhook(
+            &WebhookId(String::from("1234")),
+            "token",
+            "push",
+            &json!({"ref": "refs/heads/main"}),
+            &requests::ExecuteCompatibleWebhookQuery::default(),
+        )
+        .await
+        .unwrap();
+
+    let request = server.await.unwrap();
+    assert!(request.starts_with("POST /api/v10/webhooks/1234/token/github HTTP/1.1"));
+    assert!(request.contains("x-github-event: push\r\n"));
+}
@@ -0,0 +1,554 @@
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::codes;
+
+codes! {
+    /// Discord docs: https://discord.com/developers/docs/topics/opcodes-and-status-codes#json-json-error-codes
+    #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+    pub enum JsonErrorCode: u32 {
+        /// General error (such as a malformed request body, amongst other things).
+        GeneralError = 0,
+        /// Unknown account.
+        UnknownAccount = 10001,
+        /// Unknown application.
+        UnknownApplication = 10002,
+        /// Unknown channel.
+        UnknownChannel = 10003,
+        /// Unknown guild.
+        UnknownGuild = 10004,
+        /// Unknown integration.
+        UnknownIntegration = 10005,
+        /// Unknown invite.
+        UnknownInvite = 10006,
+        /// Unknown member.
+        UnknownMember = 10007,
+        /// Unknown message.
+        UnknownMessage = 10008,
+        /// Unknown permission overwrite.
+        UnknownPermissionOverwrite = 10009,
+        /// Unknown provider.
+        UnknownProvider = 10010,
+        /// Unknown role.
+        UnknownRole = 10011,
+        /// Unknown token.
+        UnknownToken = 10012,
+        /// Unknown user.
+        UnknownUser = 10013,
+        /// Unknown emoji.
+        UnknownEmoji = 10014,
+        /// Unknown webhook.
+        UnknownWebhook = 10015,
+        /// Unknown webhook service.
+        UnknownWebhookService = 10016,
+        /// Unknown session.
+        UnknownSession = 10020,
+        /// Unknown asset.
+        UnknownAsset = 10021,
+        /// Unknown ban.
+        UnknownBan = 10026,
+        /// Unknown SKU.
+        UnknownSku = 10027,
+        /// Unknown Store Listing.
+        UnknownStoreListing = 10028,
+        /// Unknown entitlement.
+        UnknownEntitlement = 10029,
+        /// Unknown build.
+        UnknownBuild = 10030,
+        /// Unknown lobby.
+        UnknownLobby = 10031,
+        /// Unknown branch.
+        UnknownBranch = 10032,
+        /// Unknown store directory layout.
+        UnknownStoreDirectoryLayout = 10033,
+        /// Unknown redistributable.
+        UnknownRedistributable = 10036,
+        /// Unknown gift code.
+        UnknownGiftCode = 10038,
+        /// Unknown stream.
+        UnknownStream = 10049,
+        /// Unknown premium server subscribe cooldown.
+        UnknownPremiumServerSubscribeCooldown = 10050,
+        /// Unknown guild template.
+        UnknownGuildTemplate = 10057,
+        /// Unknown discoverable server category.
+        UnknownDiscoverableServerCategory = 10059,
+        /// Unknown sticker.
+        UnknownSticker = 10060,
+        /// Unknown sticker pack.
+        UnknownStickerPack = 10061,
+        /// Unknown interaction.
+        UnknownInteraction = 10062,
+        /// Unknown application command.
+        UnknownApplicationCommand = 10063,
+        /// Unknown voice state.
+        UnknownVoiceState = 10065,
+        /// Unknown application command permissions.
+        UnknownApplicationCommandPermissions = 10066,
+        /// Unknown Stage Instance.
+        UnknownStageInstance = 10067,
+        /// Unknown Guild Member Verification Form.
+        UnknownGuildMemberVerificationForm = 10068,
+        /// Unknown Guild Welcome Screen.
+        UnknownGuildWelcomeScreen = 10069,
+        /// Unknown Guild Scheduled Event.
+        UnknownGuildScheduledEvent = 10070,
+        /// Unknown Guild Scheduled Event User.
+        UnknownGuildScheduledEventUser = 10071,
+        /// Unknown Tag.
+        UnknownTag = 10087,
+        /// Unknown sound.
+        UnknownSound = 10097,
+        /// Bots cannot use this endpoint.
+        BotsCannotUseEndpoint = 20001,
+        /// Only bots can use this endpoint.
+        OnlyBotsCanUseEndpoint = 20002,
+        /// Explicit content cannot be sent to the desired recipient(s).
+        ExplicitContentCannotBeSent = 20009,
+        /// You are not authorized to perform this action on this application.
+        NotAuthorizedForApplication = 20012,
+        /// This action cannot be performed due to slowmode rate limit.
+        SlowmodeRateLimit = 20016,
+        /// Only the owner of this account can perform this action.
+        OnlyAccountOwner = 20018,
+        /// This message cannot be edited due to announcement rate limits.
+        AnnouncementEditRateLimit = 20022,
+        /// Under minimum age.
+        UnderMinimumAge = 20024,
+        /// The channel you are writing has hit the write rate limit.
+        ChannelWriteRateLimit = 20028,
+        /// The write action you are performing on the server has hit the write rate limit.
+        ServerWriteRateLimit = 20029,
+        /// Your Stage topic, server name, server description, or channel names contain words that are not allowed.
+        DisallowedWords = 20031,
+        /// Guild premium subscription level too low.
+        GuildPremiumSubscriptionLevelTooLow = 20035,
+        /// Maximum number of guilds reached (100).
+        MaximumGuilds = 30001,
+        /// Maximum number of friends reached (1000).
+        MaximumFriends = 30002,
+        /// Maximum number of pins reached for the channel (50).
+        MaximumPins = 30003,
+        /// Maximum number of recipients reached (10).
+        MaximumRecipients = 30004,
+        /// Maximum number of guild roles reached (250).
+        MaximumGuildRoles = 30005,
+        /// Maximum number of webhooks reached (15).
+        MaximumWebhooks = 30007,
+        /// Maximum number of emojis reached.
+        MaximumEmojis = 30008,
+        /// Maximum number of reactions reached (20).
+        MaximumReactions = 30010,
+        /// Maximum number of group DMs reached (10).
+        MaximumGroupDms = 30011,
+        /// Maximum number of guild channels reached (500).
+        MaximumGuildChannels = 30013,
+        /// Maximum number of attachments in a message reached (10).
+        MaximumAttachments = 30015,
+        /// Maximum number of invites reached (1000).
+        MaximumInvites = 30016,
+        /// Maximum number of animated emojis reached.
+        MaximumAnimatedEmojis = 30018,
+        /// Maximum number of server members reached.
+        MaximumServerMembers = 30019,
+        /// Maximum number of server categories has been reached (5).
+        MaximumServerCategories = 30030,
+        /// Guild already has a template.
+        GuildAlreadyHasTemplate = 30031,
+        /// Maximum number of application commands reached.
+        MaximumApplicationCommands = 30032,
+        /// Maximum number of thread participants has been reached (1000).
+        MaximumThreadParticipants = 30033,
+        /// Maximum number of daily application command creates has been reached (200).
+        MaximumDailyApplicationCommandCreates = 30034,
+        /// Maximum number of bans for non-guild members have been exceeded.
+        MaximumNonMemberBans = 30035,
+        /// Maximum number of bans fetches has been reached.
+        MaximumBanFetches = 30037,
+        /// Maximum number of uncompleted guild scheduled events reached (100).
+        MaximumUncompletedGuildScheduledEvents = 30038,
+        /// Maximum number of stickers reached.
+        MaximumStickers = 30039,
+        /// Maximum number of prune requests has been reached. Try again later.
+        MaximumPruneRequests = 30040,
+        /// Maximum number of guild widget settings updates has been reached. Try again later.
+        MaximumGuildWidgetSettingsUpdates = 30042,
+        /// Maximum number of soundboard sounds reached.
+        MaximumSoundboardSounds = 30045,
+        /// Maximum number of edits to messages older than 1 hour reached. Try again later.
+        MaximumOldMessageEdits = 30046,
+        /// Maximum number of pinned threads in a forum channel has been reached.
+        MaximumPinnedThreads = 30047,
+        /// Maximum number of tags in a forum channel has been reached.
+        MaximumForumTags = 30048,
+        /// Bitrate is too high for channel of this type.
+        BitrateTooHigh = 30052,
+        /// Maximum number of premium emojis reached (25).
+        MaximumPremiumEmojis = 30056,
+        /// Maximum number of webhooks per guild reached (1000).
+        MaximumGuildWebhooks = 30058,
+        /// Maximum number of channel permission overwrites reached (1000).
+        MaximumChannelPermissionOverwrites = 30060,
+        /// The channels for this guild are too large.
+        GuildChannelsTooLarge = 30061,
+        /// Unauthorized. Provide a valid token and try again.
+        Unauthorized = 40001,
+        /// You need to verify your account in order to perform this action.
+        AccountVerificationRequired = 40002,
+        /// You are opening direct messages too fast.
+        OpeningDirectMessagesTooFast = 40003,
+        /// Send messages has been temporarily disabled.
+        SendMessagesTemporarilyDisabled = 40004,
+        /// Request entity too large. Try sending something smaller in size.
+        RequestEntityTooLarge = 40005,
+        /// This feature has been temporarily disabled server-side.
+        FeatureTemporarilyDisabled = 40006,
+        /// The user is banned from this guild.
+        UserBannedFromGuild = 40007,
+        /// Connection has been revoked.
+        ConnectionRevoked = 40012,
+        /// Only consumable SKUs can be consumed.
+        OnlyConsumableSkusCanBeConsumed = 40018,
+        /// You can only delete sandbox entitlements.
+        OnlySandboxEntitlementsCanBeDeleted = 40019,
+        /// Target user is not connected to voice.
+        TargetUserNotConnectedToVoice = 40032,
+        /// This message has already been crossposted.
+        MessageAlreadyCrossposted = 40033,
+        /// An application command with that name already exists.
+        ApplicationCommandNameExists = 40041,
+        /// Application interaction failed to send.
+        ApplicationInteractionFailedToSend = 40043,
+        /// Cannot send a message in a forum channel.
+        CannotSendMessageInForumChannel = 40058,
+        /// Interaction has already been acknowledged.
+        InteractionAlreadyAcknowledged = 40060,
+        /// Tag names must be unique.
+        TagNamesMustBeUnique = 40061,
+        /// Service resource is being rate limited.
+        ServiceResourceRateLimited = 40062,
+        /// There are no tags available that can be set by non-moderators.
+        NoTagsAvailableForNonModerators = 40066,
+        /// A tag is required to create a forum post in this channel.
+        TagRequiredForForumPost = 40067,
+        /// An entitlement has already been granted for this resource.
+        EntitlementAlreadyGranted = 40074,
+        /// This interaction has hit the maximum number of follow up messages.
+        MaximumFollowUpMessages = 40094,
+        /// Cloudflare is blocking your request. This can often be resolved by setting a proper User Agent.
+        CloudflareBlocked = 40333,
+        /// Missing access.
+        MissingAccess = 50001,
+        /// Invalid account type.
+        InvalidAccountType = 50002,
+        /// Cannot execute action on a DM channel.
+        CannotExecuteOnDmChannel = 50003,
+        /// Guild widget disabled.
+        GuildWidgetDisabled = 50004,
+        /// Cannot edit a message authored by another user.
+        CannotEditOtherUsersMessage = 50005,
+        /// Cannot send an empty message.
+        CannotSendEmptyMessage = 50006,
+        /// Cannot send messages to this user.
+        CannotSendMessagesToUser = 50007,
+        /// Cannot send messages in a non-text channel.
+        CannotSendMessagesInNonTextChannel = 50008,
+        /// Channel verification level is too high for you to gain access.
+        ChannelVerificationLevelTooHigh = 50009,
+        /// OAuth2 application does not have a bot.
+        OAuth2ApplicationHasNoBot = 50010,
+        /// OAuth2 application limit reached.
+        OAuth2ApplicationLimitReached = 50011,
+        /// Invalid OAuth2 state.
+        InvalidOAuth2State = 50012,
+        /// You lack permissions to perform that action.
+        MissingPermissions = 50013,
+        /// Invalid authentication token provided.
+        InvalidAuthenticationToken = 50014,
+        /// Note was too long.
+        NoteTooLong = 50015,
+        /// Provided too few or too many messages to delete. Must provide at least 2 and fewer than 100 messages to delete.
+        InvalidBulkDeleteCount = 50016,
+        /// Invalid MFA Level.
+        InvalidMfaLevel = 50017,
+        /// A message can only be pinned to the channel it was sent in.
+        MessagePinnedInWrongChannel = 50019,
+        /// Invite code was either invalid or taken.
+        InvalidInviteCode = 50020,
+        /// Cannot execute action on a system message.
+        CannotExecuteOnSystemMessage = 50021,
+        /// Cannot execute action on this channel type.
+        CannotExecuteOnChannelType = 50024,
+        /// Invalid OAuth2 access token provided.
+        InvalidOAuth2AccessToken = 50025,
+        /// Missing required OAuth2 scope.
+        MissingOAuth2Scope = 50026,
+        /// Invalid webhook token provided.
+        InvalidWebhookToken = 50027,
+        /// Invalid role.
+        InvalidRole = 50028,
+        /// Invalid Recipient(s).
+        InvalidRecipients = 50033,
+        /// A message provided was too old to bulk delete.
+        MessageTooOldToBulkDelete = 50034,
+        /// Invalid form body (returned for both `application/json` and `multipart/form-data` bodies), or invalid `Content-Type` provided.
+        InvalidFormBody = 50035,
+        /// An invite was accepted to a guild the application's bot is not in.
+        InviteAcceptedToGuildWithoutBot = 50036,
+        /// Invalid Activity Action.
+        InvalidActivityAction = 50039,
+        /// Invalid API version provided.
+        InvalidApiVersion = 50041,
+        /// File uploaded exceeds the maximum size.
+        FileTooLarge = 50045,
+        /// Invalid file uploaded.
+        InvalidFileUploaded = 50046,
+        /// Cannot self-redeem this gift.
+        CannotSelfRedeemGift = 50054,
+        /// Invalid Guild.
+        InvalidGuild = 50055,
+        /// Invalid SKU.
+        InvalidSku = 50057,
+        /// Invalid request origin.
+        InvalidRequestOrigin = 50067,
+        /// Invalid message type.
+        InvalidMessageType = 50068,
+        /// Payment source required to redeem gift.
+        PaymentSourceRequired = 50070,
+        /// Cannot modify a system webhook.
+        CannotModifySystemWebhook = 50073,
+        /// Cannot delete a channel required for Community guilds.
+        CannotDeleteCommunityChannel = 50074,
+        /// Cannot edit stickers within a message.
+        CannotEditMessageStickers = 50080,
+        /// Invalid sticker sent.
+        InvalidSticker = 50081,
+        /// Tried to perform an operation on an archived thread.
+        ThreadArchived = 50083,
+        /// Invalid thread notification settings.
+        InvalidThreadNotificationSettings = 50084,
+        /// `before` value is earlier than the thread creation date.
+        BeforeEarlierThanThreadCreation = 50085,
+        /// Community server channels must be text channels.
+        CommunityChannelsMustBeText = 50086,
+        /// The entity type of the event is different from the entity you are trying to start the event for.
+        EventEntityTypeMismatch = 50091,
+        /// This server is not available in your location.
+        ServerUnavailableInLocation = 50095,
+        /// This server needs monetization enabled in order to perform this action.
+        MonetizationRequired = 50097,
+        /// This server needs more boosts to perform this action.
+        MoreBoostsRequired = 50101,
+        /// The request body contains invalid JSON.
+        InvalidJson = 50109,
+        /// The provided file is invalid.
+        InvalidFile = 50110,
+        /// The provided file type is invalid.
+        InvalidFileType = 50123,
+        /// The provided file duration exceeds maximum of 5.2 seconds.
+        FileDurationTooLong = 50124,
+        /// Owner cannot be pending member.
+        OwnerCannotBePendingMember = 50131,
+        /// Ownership cannot be transferred to a bot user.
+        CannotTransferOwnershipToBot = 50132,
+        /// Failed to resize asset below the maximum size: 262144.
+        AssetResizeFailed = 50138,
+        /// Cannot mix subscription and non subscription roles for an emoji.
+        CannotMixSubscriptionRoles = 50144,
+        /// Cannot convert between premium emoji and normal emoji.
+        CannotConvertPremiumEmoji = 50145,
+        /// Uploaded file not found.
+        UploadedFileNotFound = 50146,
+        /// The specified emoji is invalid.
+        InvalidEmoji = 50151,
+        /// Voice messages do not support additional content.
+        VoiceMessagesNoAdditionalContent = 50159,
+        /// Voice messages must have a single audio attachment.
+        VoiceMessagesSingleAudioAttachment = 50160,
+        /// Voice messages must have supporting metadata.
+        VoiceMessagesMetadataRequired = 50161,
+        /// Voice messages cannot be edited.
+        VoiceMessagesCannotBeEdited = 50162,
+        /// Cannot delete guild subscription integration.
+        CannotDeleteGuildSubscriptionIntegration = 50163,
+        /// You cannot send voice messages in this channel.
+        CannotSendVoiceMessagesInChannel = 50173,
+        /// The user account must first be verified.
+        UserAccountNotVerified = 50178,
+        /// The provided file does not have a valid duration.
+        InvalidFileDuration = 50192,
+        /// You do not have permission to send this sticker.
+        NoPermissionToSendSticker = 50600,
+        /// Two factor is required for this operation.
+        TwoFactorRequired = 60003,
+        /// No users with DiscordTag exist.
+        NoUsersWithDiscordTag = 80004,
+        /// Reaction was blocked.
+        ReactionBlocked = 90001,
+        /// User cannot use burst reactions.
+        CannotUseBurstReactions = 90002,
+        /// Application not yet available. Try again later.
+        ApplicationNotYetAvailable = 110001,
+        /// API resource is currently overloaded. Try again a little later.
+        ApiResourceOverloaded = 130000,
+        /// The Stage is already open.
+        StageAlreadyOpen = 150006,
+        /// Cannot reply without permission to read message history.
+        CannotReplyWithoutReadMessageHistory = 160002,
+        /// A thread has already been created for this message.
+        ThreadAlreadyCreatedForMessage = 160004,
+        /// Thread is locked.
+        ThreadLocked = 160005,
+        /// Maximum number of active threads reached.
+        MaximumActiveThreads = 160006,
+        /// Maximum number of active announcement threads reached.
+        MaximumActiveAnnouncementThreads = 160007,
+        /// Invalid JSON for uploaded Lottie file.
+        InvalidLottieJson = 170001,
+        /// Uploaded Lotties cannot contain rasterized images such as PNG or JPEG.
+        LottieContainsRasterizedImages = 170002,
+        /// Sticker maximum framerate exceeded.
+        StickerMaximumFramerateExceeded = 170003,
+        /// Sticker frame count exceeds maximum of 1000 frames.
+        StickerMaximumFramesExceeded = 170004,
+        /// Lottie animation maximum dimensions exceeded.
+        LottieMaximumDimensionsExceeded = 170005,
+        /// Sticker frame rate is either too small or too large.
+        StickerFrameRateOutOfRange = 170006,
+        /// Sticker animation duration exceeds maximum of 5 seconds.
+        StickerAnimationTooLong = 170007,
+        /// Cannot update a finished event.
+        CannotUpdateFinishedEvent = 180000,
+        /// Failed to create stage needed for stage event.
+        FailedToCreateStageForEvent = 180002,
+        /// Message was blocked by automatic moderation.
+        MessageBlockedByAutoModeration = 200000,
+        /// Title was blocked by automatic moderation.
+        TitleBlockedByAutoModeration = 200001,
+        /// Webhooks posted to forum channels must have a thread_name or thread_id.
+        ForumWebhookThreadRequired = 220001,
+        /// Webhooks posted to forum channels cannot have both a thread_name and thread_id.
+        ForumWebhookThreadConflict = 220002,
+        /// Webhooks can only create threads in forum channels.
+        WebhookThreadsOnlyInForums = 220003,
+        /// Webhook services cannot be used in forum channels.
+        WebhookServicesNotInForums = 220004,
+        /// Message blocked by harmful links filter.
+        MessageBlockedByHarmfulLinksFilter = 240000,
+        /// Cannot enable onboarding, requirements are not met.
+        CannotEnableOnboarding = 350000,
+        /// Cannot update onboarding while below requirements.
+        CannotUpdateOnboarding = 350001,
+        /// Failed to ban users.
+        FailedToBanUsers = 500000,
+        /// Poll voting blocked.
+        PollVotingBlocked = 520000,
+        /// Poll expired.
+        PollExpired = 520001,
+        /// Invalid channel type for poll creation.
+        InvalidPollChannelType = 520002,
+        /// Cannot edit a poll message.
+        CannotEditPollMessage = 520003,
+        /// Cannot use an emoji included with the poll.
+        CannotUsePollEmoji = 520004,
+        /// Cannot expire a non-poll message.
+        CannotExpireNonPollMessage = 520006,
+    }
+}
+
+/// Validation error for a single field of a request.
+#[derive(Clone, Debug, Deserialize)]
+pub struct FieldError {
+    /// Such as `BASE_TYPE_REQUIRED`.
+    pub code: String,
+    pub message: String,
+}
+
+/// Discord docs: https://discord.com/developers/docs/reference#error-messages
+#[derive(Deserialize)]
+pub(super) struct ErrorResponse {
+    pub code: JsonErrorCode,
+    pub message: String,
+    #[serde(default)]
+    pub errors: Option<Value>,
+}
+
+/// Flattens the nested `errors` object into paths to the fields with errors,
+/// such as `activities.0.platform`.
+/// Errors for the request as a whole (rather than a field) have an empty path.
+pub(super) fn flatten_errors(errors: Option<Value>) -> BTreeMap<String, Vec<FieldError>> {
+    let mut flattened = BTreeMap::new();
+    if let Some(errors) = errors {
+        flatten_into(&mut flattened, String::new(), errors);
+    }
+    flattened
+}
+
+fn flatten_into(flattened: &mut BTreeMap<String, Vec<FieldError>>, path: String, value: Value) {
+    let Value::Object(fields) = value else {
+        return;
+    };
+
+    for (key, value) in fields {
+        if key == "_errors" {
+            if let Ok(errors) = serde_json::from_value::<Vec<FieldError>>(value) {
+                flattened.entry(path.clone()).or_default().extend(errors);
+            }
+        } else if path.is_empty() {
+            flatten_into(flattened, key, value);
+        } else {
+            flatten_into(flattened, format!("{path}.{key}"), value);
+        }
+    }
+}
+
+/// JSON taken from https://discord.com/developers/docs/reference#error-messages-array-error
+#[test]
+fn test_error_response() {
+    let json = r#"{
+        "code": 50035,
+        "errors": {
+            "activities": {
+                "0": {
+                    "platform": {
+                        "_errors": [
+                            {
+                                "code": "BASE_TYPE_CHOICES",
+                                "message": "Value must be one of ('desktop', 'android', 'ios')."
+                            }
+                        ]
+                    },
+                    "type": {
+                        "_errors": [
+                            {
+                                "code": "BASE_TYPE_CHOICES",
+                                "message": "Value must be one of (0, 1, 2, 3, 4, 5)."
+                            }
+                        ]
+                    }
+                }
+            }
+        },
+        "message": "Invalid Form Body"
+    }"#;
+
+    let error: ErrorResponse = serde_json::from_str(json).unwrap();
+    assert_eq!(JsonErrorCode::InvalidFormBody, error.code);
+    assert_eq!("Invalid Form Body", error.message);
+
+    let errors = flatten_errors(error.errors);
+    assert_eq!(
+        vec!["activities.0.platform", "activities.0.type"],
+        errors.keys().collect::<Vec<_>>()
+    );
+    assert_eq!("BASE_TYPE_CHOICES", errors["activities.0.type"][0].code);
+
+    let error: ErrorResponse =
+        serde_json::from_str(r#"{"code": 10003, "message": "Unknown Channel"}"#).unwrap();
+    assert_eq!(JsonErrorCode::UnknownChannel, error.code);
+    assert!(flatten_errors(error.errors).is_empty());
+
+    assert_eq!(JsonErrorCode::Unknown(1), JsonErrorCode::from_code(1));
+}
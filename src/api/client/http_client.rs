@@ -1,43 +1,242 @@
-use std::collections::HashMap;
+use std::{sync::Arc, time::Duration};
 
-use reqwest::{Client, Method, Response};
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use reqwest::{
+    header::HeaderValue, multipart::Form, Client, Method, RequestBuilder, Response, StatusCode,
+};
+use serde::{de::DeserializeOwned, Serialize};
 use url::Url;
 
-use super::Result;
+use super::{
+    attachment::{multipart_form, AttachmentFile},
+    ratelimit::{RatelimitInfo, RatelimitResponse, Ratelimiter, Route},
+    ApiError, Result,
+};
 
 pub struct HttpClient {
+    /// Includes the API version.
     base_url: Url,
-    base_query: Vec<(&'static str, &'static str)>,
     client: Client,
+    ratelimiter: Arc<dyn Ratelimiter>,
+    max_retries: u32,
 }
 
 impl HttpClient {
-    pub fn new(base_url: Url) -> Result<HttpClient> {
-        Ok(HttpClient {
+    /// Wait before the first retry of a 429 without a `retry_after`, doubling with each retry.
+    const FALLBACK_RETRY_AFTER: Duration = Duration::from_secs(1);
+
+    pub fn new(
+        base_url: Url,
+        client: Client,
+        ratelimiter: Arc<dyn Ratelimiter>,
+        max_retries: u32,
+    ) -> HttpClient {
+        HttpClient {
             base_url,
-            base_query: vec![],
-            client: reqwest::Client::builder().build()?,
-        })
+            client,
+            ratelimiter,
+            max_retries,
+        }
     }
 
-    pub async fn request(
-        &self,
-        method: Method,
-        api_path: &str,
-        query: &HashMap<&str, &str>,
-    ) -> Result<Response> {
+    pub fn request(&self, method: Method, api_path: &str) -> Result<HttpRequest<'_>> {
         let url = self.base_url.join(api_path.trim_start_matches('/'))?;
 
-        Ok(self
-            .client
-            .request(method, url)
-            .query(query)
-            .query(&self.base_query)
-            .send()
-            .await?)
+        Ok(HttpRequest {
+            http_client: self,
+            route: Route::new(&method, api_path),
+            request: self.client.request(method, url),
+        })
+    }
+
+    pub fn get(&self, api_path: &str) -> Result<HttpRequest<'_>> {
+        self.request(Method::GET, api_path)
+    }
+
+    pub fn post(&self, api_path: &str) -> Result<HttpRequest<'_>> {
+        self.request(Method::POST, api_path)
+    }
+
+    pub fn put(&self, api_path: &str) -> Result<HttpRequest<'_>> {
+        self.request(Method::PUT, api_path)
+    }
+
+    pub fn patch(&self, api_path: &str) -> Result<HttpRequest<'_>> {
+        self.request(Method::PATCH, api_path)
+    }
+
+    pub fn delete(&self, api_path: &str) -> Result<HttpRequest<'_>> {
+        self.request(Method::DELETE, api_path)
+    }
+
+    /// Sends the request once the rate limiter allows it,
+    /// retrying up to `max_retries` times if rate limited anyway.
+    /// Requests with bodies that can't be cloned (streams) aren't retried.
+    async fn send(&self, route: &Route, request: RequestBuilder) -> Result<Response> {
+        let mut next_request = Some(request);
+        let mut retries = 0;
+        loop {
+            let request = next_request
+                .take()
+                .expect("Only retried when the request could be cloned");
+            next_request = request.try_clone();
+
+            self.ratelimiter.acquire(route).await;
+            let response = request.send().await?;
+            let mut info = RatelimitInfo::from_headers(response.headers());
+
+            if response.status() != StatusCode::TOO_MANY_REQUESTS {
+                self.ratelimiter.update(route, info).await;
+                return Ok(response);
+            }
+
+            // Fall back to the `Retry-After` header if the body isn't from Discord (eg, Cloudflare).
+            if let Ok(body) = response.json::<RatelimitResponse>().await {
+                info.retry_after = Duration::try_from_secs_f64(body.retry_after).ok();
+                info.global = body.global;
+            }
+            // Back off anyway when neither says how long to wait, rather than retrying at once.
+            let retry_after = *info
+                .retry_after
+                .get_or_insert(Self::FALLBACK_RETRY_AFTER * 2u32.saturating_pow(retries));
+            let global = info.global;
+            self.ratelimiter.update(route, info).await;
+
+            if retries >= self.max_retries || next_request.is_none() {
+                return Err(ApiError::Ratelimited {
+                    retry_after,
+                    global,
+                });
+            }
+            retries += 1;
+        }
+    }
+}
+
+/// Unreserved characters (RFC 3986) are left as is, everything else is percent-encoded.
+pub(super) const UNRESERVED: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+/// Request to the API, sent through the rate limiter.
+pub struct HttpRequest<'a> {
+    http_client: &'a HttpClient,
+    route: Route,
+    request: RequestBuilder,
+}
+
+impl HttpRequest<'_> {
+    /// Query parameters, where fields set to `None` are left out.
+    pub fn query<Q: Serialize + ?Sized>(mut self, query: &Q) -> Self {
+        self.request = self.request.query(query);
+        self
+    }
+
+    pub fn json<B: Serialize + ?Sized>(mut self, body: &B) -> Self {
+        self.request = self.request.json(body);
+        self
+    }
+
+    /// Sends the body as JSON, or as `multipart/form-data` alongside any files.
+    ///
+    /// Discord docs: https://discord.com/developers/docs/reference#uploading-files
+    pub fn json_with_files<B: Serialize + ?Sized>(
+        self,
+        body: &B,
+        files: Vec<AttachmentFile>,
+    ) -> Result<Self> {
+        if files.is_empty() {
+            return Ok(self.json(body));
+        }
+
+        Ok(self.form(multipart_form(body, None, files)?))
+    }
+
+    /// Like `json_with_files`, but with `attachments` in the object under `key`
+    /// (eg, `data` for interaction responses) rather than at the top of the body.
+    pub fn json_with_nested_files<B: Serialize + ?Sized>(
+        self,
+        body: &B,
+        key: &str,
+        files: Vec<AttachmentFile>,
+    ) -> Result<Self> {
+        if files.is_empty() {
+            return Ok(self.json(body));
+        }
+
+        Ok(self.form(multipart_form(body, Some(key), files)?))
+    }
+
+    pub fn form(mut self, form: Form) -> Self {
+        self.request = self.request.multipart(form);
+        self
+    }
+
+    pub fn header(mut self, name: &'static str, value: &str) -> Result<Self> {
+        self.request = self.request.header(name, HeaderValue::try_from(value)?);
+        Ok(self)
+    }
+
+    /// Sets the `X-Audit-Log-Reason` header for endpoints that support it.
+    ///
+    /// Discord docs: https://discord.com/developers/docs/resources/audit-log#audit-log-entry-object-audit-log-change-exceptions
+    pub fn audit_log_reason(mut self, reason: Option<&str>) -> Self {
+        if let Some(reason) = reason {
+            self.request = self.request.header(
+                "x-audit-log-reason",
+                utf8_percent_encode(reason, UNRESERVED).to_string(),
+            );
+        }
+        self
+    }
+
+    pub async fn send(self) -> Result<Response> {
+        self.http_client.send(&self.route, self.request).await
+    }
+
+    /// Sends the request and deserializes the JSON response,
+    /// or returns the error for an unsuccessful status code.
+    pub async fn send_json<T: DeserializeOwned>(self) -> Result<T> {
+        let response = self.send().await?;
+        if !response.status().is_success() {
+            return Err(ApiError::from_response(response).await);
+        }
+
+        Ok(response.json::<T>().await?)
     }
 
-    pub async fn get(&self, api_path: &str, query: &HashMap<&str, &str>) -> Result<Response> {
-        self.request(Method::GET, api_path, query).await
+    /// Sends a request with no response body expected (usually `204 No Content`),
+    /// returning the error for an unsuccessful status code.
+    pub async fn send_empty(self) -> Result<()> {
+        let response = self.send().await?;
+        if !response.status().is_success() {
+            return Err(ApiError::from_response(response).await);
+        }
+
+        Ok(())
     }
 }
+
+#[test]
+fn test_audit_log_reason() {
+    let http_client = HttpClient::new(
+        Url::parse("https://discord.com/api/v10/").unwrap(),
+        Client::new(),
+        Arc::new(super::InMemoryRatelimiter::default()),
+        0,
+    );
+    let request = http_client
+        .delete("/guilds/1/bans/2")
+        .unwrap()
+        .audit_log_reason(Some("Spam: \"free nitro\" 🎁"))
+        .request
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        "Spam%3A%20%22free%20nitro%22%20%F0%9F%8E%81",
+        request.headers()["x-audit-log-reason"]
+    );
+}
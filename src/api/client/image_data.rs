@@ -0,0 +1,40 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::{Serialize, Serializer};
+
+/// Image uploaded as a data URI, such as a group DM or role icon.
+///
+/// Discord docs: https://discord.com/developers/docs/reference#image-data
+#[derive(Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct ImageData {
+    data_uri: String,
+}
+
+impl ImageData {
+    /// `content_type` is the MIME type of the image, such as `image/png`, `image/jpeg`,
+    /// or `image/gif`.
+    pub fn new(content_type: &str, bytes: &[u8]) -> Self {
+        ImageData {
+            data_uri: format!("data:{content_type};base64,{}", STANDARD.encode(bytes)),
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.data_uri
+    }
+}
+
+impl Serialize for ImageData {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.data_uri)
+    }
+}
+
+#[test]
+fn test_image_data() {
+    let image = ImageData::new("image/png", b"\x89PNG");
+    assert_eq!(
+        r#""data:image/png;base64,iVBORw==""#,
+        serde_json::to_string(&image).unwrap()
+    );
+}
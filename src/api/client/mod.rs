@@ -1,14 +1,33 @@
-use std::rc::Rc;
+use std::{collections::BTreeMap, sync::Arc, time::Duration};
 
-use reqwest::StatusCode;
+use reqwest::{Response, StatusCode};
 use thiserror::Error;
-use url::Url;
 
-use crate::api::client::endpoints::gateway::Gateway;
+use crate::api::client::endpoints::{
+    application_commands::ApplicationCommands, audit_logs::AuditLogs,
+    auto_moderation::AutoModeration, bans::Bans, channels::Channels, gateway::Gateway,
+    guilds::Guilds, interactions::Interactions, members::Members, messages::Messages,
+    reactions::Reactions, roles::Roles, stickers::Stickers, webhooks::Webhooks,
+};
 
 use self::http_client::HttpClient;
 
+mod attachment;
+mod builder;
+mod error;
 mod http_client;
+mod image_data;
+mod ratelimit;
+mod webhook_client;
+
+pub use attachment::{AttachmentContent, AttachmentFile};
+pub use builder::{ApiBuilder, ApiVersion, Token};
+pub use error::{FieldError, JsonErrorCode};
+pub use image_data::ImageData;
+pub use ratelimit::{
+    InMemoryRatelimiter, RatelimitFuture, RatelimitInfo, RatelimitScope, Ratelimiter, Route,
+};
+pub use webhook_client::WebhookClient;
 
 pub mod endpoints;
 
@@ -20,6 +39,26 @@ pub enum ApiError {
     ReqwestError(#[from] reqwest::Error),
     #[error("Failed to parse a URL")]
     UrlParseError(#[from] url::ParseError),
+    #[error("Failed to serialize a request body")]
+    JsonError(#[from] serde_json::Error),
+    #[error("Invalid header value, likely from a token or user agent with invalid characters")]
+    InvalidHeaderValue(#[from] reqwest::header::InvalidHeaderValue),
+    #[error("Emoji needs an ID or a name to be used as a reaction")]
+    InvalidReactionEmoji,
+    #[error("Webhook URL should end with `/webhooks/{{id}}/{{token}}`")]
+    InvalidWebhookUrl,
+    #[error("Invalid trigger metadata for the auto moderation rule: {0}")]
+    InvalidTriggerMetadata(#[from] endpoints::auto_moderation::requests::TriggerMetadataError),
+    #[error("Rate limited for {retry_after:?} after retrying")]
+    Ratelimited { retry_after: Duration, global: bool },
+    #[error("Discord returned error {} (HTTP {status_code}): {message}", code.code())]
+    Discord {
+        status_code: StatusCode,
+        code: JsonErrorCode,
+        message: String,
+        /// Errors for each invalid field, keyed by the path to the field (eg, `embeds.0.title`).
+        errors: BTreeMap<String, Vec<FieldError>>,
+    },
     #[error("Received an unexpected response from the API (HTTP {status_code}): {response}")]
     UnexpectedResponse {
         status_code: StatusCode,
@@ -27,19 +66,149 @@ pub enum ApiError {
     },
 }
 
+impl ApiError {
+    /// Builds the error for a response with an unexpected status code,
+    /// parsing Discord's JSON error body if there is one.
+    pub(crate) async fn from_response(response: Response) -> ApiError {
+        let status_code = response.status();
+        let response = match response.text().await {
+            Ok(response) => response,
+            Err(error) => return error.into(),
+        };
+
+        match serde_json::from_str::<error::ErrorResponse>(&response) {
+            Ok(error) => ApiError::Discord {
+                status_code,
+                code: error.code,
+                message: error.message,
+                errors: error::flatten_errors(error.errors),
+            },
+            Err(_) => ApiError::UnexpectedResponse {
+                status_code,
+                response,
+            },
+        }
+    }
+
+    pub fn json_error_code(&self) -> Option<JsonErrorCode> {
+        match self {
+            ApiError::Discord { code, .. } => Some(*code),
+            _ => None,
+        }
+    }
+}
+
+/// Client for the Discord API.
+///
+/// Clones share the same connection pool and rate limits,
+/// so clone this rather than building a new one for each task.
+#[derive(Clone)]
 pub struct Api {
+    application_commands: ApplicationCommands,
+    audit_logs: AuditLogs,
+    auto_moderation: AutoModeration,
+    bans: Bans,
+    channels: Channels,
     gateway: Gateway,
+    guilds: Guilds,
+    interactions: Interactions,
+    members: Members,
+    messages: Messages,
+    reactions: Reactions,
+    roles: Roles,
+    stickers: Stickers,
+    webhooks: Webhooks,
 }
 
 impl Api {
-    pub fn new(base_url: Url) -> Result<Api> {
-        let http_client = Rc::new(HttpClient::new(base_url)?);
-        Ok(Api {
-            gateway: Gateway::new(http_client),
-        })
+    pub fn builder() -> ApiBuilder {
+        ApiBuilder::new()
+    }
+
+    fn from_http_client(http_client: HttpClient) -> Api {
+        let http_client = Arc::new(http_client);
+        Api {
+            application_commands: ApplicationCommands::new(http_client.clone()),
+            audit_logs: AuditLogs::new(http_client.clone()),
+            auto_moderation: AutoModeration::new(http_client.clone()),
+            bans: Bans::new(http_client.clone()),
+            channels: Channels::new(http_client.clone()),
+            gateway: Gateway::new(http_client.clone()),
+            guilds: Guilds::new(http_client.clone()),
+            interactions: Interactions::new(http_client.clone()),
+            members: Members::new(http_client.clone()),
+            messages: Messages::new(http_client.clone()),
+            reactions: Reactions::new(http_client.clone()),
+            roles: Roles::new(http_client.clone()),
+            stickers: Stickers::new(http_client.clone()),
+            webhooks: Webhooks::new(http_client),
+        }
+    }
+
+    pub fn application_commands(&self) -> &ApplicationCommands {
+        &self.application_commands
+    }
+
+    pub fn audit_logs(&self) -> &AuditLogs {
+        &self.audit_logs
+    }
+
+    pub fn auto_moderation(&self) -> &AutoModeration {
+        &self.auto_moderation
+    }
+
+    pub fn bans(&self) -> &Bans {
+        &self.bans
+    }
+
+    pub fn channels(&self) -> &Channels {
+        &self.channels
     }
 
     pub fn gateway(&self) -> &Gateway {
         &self.gateway
     }
+
+    pub fn guilds(&self) -> &Guilds {
+        &self.guilds
+    }
+
+    pub fn interactions(&self) -> &Interactions {
+        &self.interactions
+    }
+
+    pub fn members(&self) -> &Members {
+        &self.members
+    }
+
+    pub fn messages(&self) -> &Messages {
+        &self.messages
+    }
+
+    pub fn reactions(&self) -> &Reactions {
+        &self.reactions
+    }
+
+    pub fn roles(&self) -> &Roles {
+        &self.roles
+    }
+
+    pub fn stickers(&self) -> &Stickers {
+        &self.stickers
+    }
+
+    pub fn webhooks(&self) -> &Webhooks {
+        &self.webhooks
+    }
+}
+
+#[test]
+fn test_api_is_shareable() {
+    fn assert_shareable<T: Clone + Send + Sync + 'static>() {}
+    assert_shareable::<Api>();
+
+    // Requests can be sent from spawned tasks.
+    fn assert_send<T: Send>(_: T) {}
+    let api = Api::builder().build().unwrap();
+    assert_send(api.gateway().get_gateway());
 }
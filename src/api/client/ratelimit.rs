@@ -0,0 +1,392 @@
+use std::{collections::HashMap, future::Future, pin::Pin, sync::Mutex, time::Duration};
+
+use reqwest::{header::HeaderMap, Method};
+use serde::Deserialize;
+use tokio::time::{sleep, Instant};
+
+pub type RatelimitFuture<'a> = Pin<Box<dyn Future<Output = ()> + Send + 'a>>;
+
+/// Tracks rate limits across requests.
+///
+/// The default `InMemoryRatelimiter` only knows about requests sent by the `Api` it belongs to
+/// (and its clones). Implement this to share rate limits between processes,
+/// such as through a database all of them can reach.
+///
+/// Discord docs: https://discord.com/developers/docs/topics/rate-limits
+pub trait Ratelimiter: Send + Sync {
+    /// Waits until a request to the route may be sent, reserving it before returning.
+    fn acquire<'a>(&'a self, route: &'a Route) -> RatelimitFuture<'a>;
+
+    /// Updates the rate limits from the response to a request to the route.
+    fn update<'a>(&'a self, route: &'a Route, info: RatelimitInfo) -> RatelimitFuture<'a>;
+}
+
+/// Identifies the rate limit bucket a request falls into before its bucket hash is known.
+///
+/// The method and path are kept, except IDs and emojis are replaced with placeholders,
+/// other than those in the major parameter (channel ID, guild ID, or webhook ID and token)
+/// which get separate rate limits.
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct Route {
+    key: String,
+    major_parameter: String,
+    global: bool,
+}
+
+impl Route {
+    pub fn new(method: &Method, api_path: &str) -> Self {
+        let path = api_path.split('?').next().unwrap_or_default();
+        let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+
+        let major_length = match segments.as_slice() {
+            ["channels" | "guilds", _, ..] => 2,
+            ["webhooks" | "interactions", _, _, ..] => 3,
+            ["webhooks", _] => 2,
+            _ => 0,
+        };
+        let major_parameter = segments[..major_length].join("/");
+
+        let mut key = format!("{method} ");
+        for (index, segment) in segments.iter().enumerate() {
+            key.push('/');
+            if index < major_length {
+                key.push_str(segment);
+            } else if index > 0 && segments[index - 1] == "reactions" {
+                key.push_str(":reaction");
+            } else if !segment.is_empty() && segment.bytes().all(|byte| byte.is_ascii_digit()) {
+                key.push_str(":id");
+            } else {
+                key.push_str(segment);
+            }
+        }
+
+        Route {
+            key,
+            major_parameter,
+            global: segments[0] != "interactions",
+        }
+    }
+
+    /// Key for the route, including the major parameter.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    pub fn major_parameter(&self) -> &str {
+        &self.major_parameter
+    }
+
+    /// Whether requests to the route count towards the global rate limit,
+    /// which interaction callbacks aren't bound by.
+    pub fn is_global(&self) -> bool {
+        self.global
+    }
+}
+
+/// Discord docs: https://discord.com/developers/docs/topics/rate-limits#header-format-rate-limit-header-examples
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub enum RatelimitScope {
+    User,
+    Global,
+    /// Shared with other users, so it doesn't count towards the invalid request limit.
+    Shared,
+}
+
+/// Rate limit details from the headers (and body, for 429s) of a response.
+///
+/// Discord docs: https://discord.com/developers/docs/topics/rate-limits#header-format
+#[derive(Default)]
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct RatelimitInfo {
+    /// Hash identifying the bucket, shared by every route with the same limits.
+    pub bucket: Option<String>,
+    pub limit: Option<u32>,
+    pub remaining: Option<u32>,
+    pub reset_after: Option<Duration>,
+    /// Set when the global rate limit was hit instead of the route's.
+    pub global: bool,
+    pub scope: Option<RatelimitScope>,
+    /// Set when the request was rate limited (HTTP 429).
+    pub retry_after: Option<Duration>,
+}
+
+impl RatelimitInfo {
+    pub fn from_headers(headers: &HeaderMap) -> Self {
+        let header = |name: &str| headers.get(name).and_then(|value| value.to_str().ok());
+
+        RatelimitInfo {
+            bucket: header("x-ratelimit-bucket").map(String::from),
+            limit: header("x-ratelimit-limit").and_then(|value| value.parse().ok()),
+            remaining: header("x-ratelimit-remaining").and_then(|value| value.parse().ok()),
+            reset_after: header("x-ratelimit-reset-after")
+                .and_then(|value| value.parse().ok())
+                .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok()),
+            global: header("x-ratelimit-global") == Some("true"),
+            scope: match header("x-ratelimit-scope") {
+                Some("user") => Some(RatelimitScope::User),
+                Some("global") => Some(RatelimitScope::Global),
+                Some("shared") => Some(RatelimitScope::Shared),
+                _ => None,
+            },
+            retry_after: header("retry-after")
+                .and_then(|value| value.parse().ok())
+                .map(Duration::from_secs),
+        }
+    }
+}
+
+/// Body of a 429 response.
+///
+/// Discord docs: https://discord.com/developers/docs/topics/rate-limits#exceeding-a-rate-limit-rate-limit-response-structure
+#[derive(Deserialize)]
+pub(super) struct RatelimitResponse {
+    /// Seconds to wait before retrying.
+    pub retry_after: f64,
+    pub global: bool,
+}
+
+/// Rate limits known to this process.
+///
+/// Buckets are learned from response headers, and the global limit of 50 requests per second
+/// is tracked locally as Discord doesn't send headers for it until it's exceeded.
+pub struct InMemoryRatelimiter {
+    global_limit: u32,
+    state: Mutex<RatelimitState>,
+}
+
+struct RatelimitState {
+    /// Bucket hashes learned for each route key.
+    bucket_hashes: HashMap<String, String>,
+    buckets: HashMap<String, Bucket>,
+    global_window_start: Instant,
+    global_window_count: u32,
+    global_reset_at: Option<Instant>,
+}
+
+struct Bucket {
+    remaining: u32,
+    reset_at: Instant,
+}
+
+impl Default for InMemoryRatelimiter {
+    fn default() -> Self {
+        Self::new(Self::GLOBAL_LIMIT)
+    }
+}
+
+impl InMemoryRatelimiter {
+    pub const GLOBAL_LIMIT: u32 = 50;
+    const GLOBAL_WINDOW: Duration = Duration::from_secs(1);
+
+    /// Large bots may have a higher global limit than the default of 50 requests per second.
+    pub fn new(global_limit: u32) -> Self {
+        InMemoryRatelimiter {
+            global_limit,
+            state: Mutex::new(RatelimitState {
+                bucket_hashes: HashMap::new(),
+                buckets: HashMap::new(),
+                global_window_start: Instant::now(),
+                global_window_count: 0,
+                global_reset_at: None,
+            }),
+        }
+    }
+
+    /// Reserves a request if one can be sent, otherwise returns how long to wait before trying again.
+    fn try_acquire(&self, route: &Route) -> Option<Duration> {
+        let mut state = self
+            .state
+            .lock()
+            .expect("Rate limit state shouldn't be poisoned");
+        let now = Instant::now();
+
+        if route.global {
+            if let Some(reset_at) = state.global_reset_at {
+                if reset_at > now {
+                    return Some(reset_at - now);
+                }
+                state.global_reset_at = None;
+            }
+
+            if now.duration_since(state.global_window_start) >= Self::GLOBAL_WINDOW {
+                state.global_window_start = now;
+                state.global_window_count = 0;
+            } else if state.global_window_count >= self.global_limit {
+                return Some(state.global_window_start + Self::GLOBAL_WINDOW - now);
+            }
+        }
+
+        let bucket_id = state.bucket_id(route);
+        match state.buckets.get_mut(&bucket_id) {
+            Some(bucket) if bucket.reset_at <= now => {
+                state.buckets.remove(&bucket_id);
+            }
+            Some(bucket) if bucket.remaining == 0 => return Some(bucket.reset_at - now),
+            Some(bucket) => bucket.remaining -= 1,
+            None => {}
+        }
+
+        if route.global {
+            state.global_window_count += 1;
+        }
+        None
+    }
+}
+
+impl RatelimitState {
+    fn bucket_id(&self, route: &Route) -> String {
+        match self.bucket_hashes.get(&route.key) {
+            Some(hash) => format!("{hash}:{}", route.major_parameter),
+            None => route.key.clone(),
+        }
+    }
+}
+
+impl Ratelimiter for InMemoryRatelimiter {
+    fn acquire<'a>(&'a self, route: &'a Route) -> RatelimitFuture<'a> {
+        Box::pin(async move {
+            while let Some(delay) = self.try_acquire(route) {
+                sleep(delay).await;
+            }
+        })
+    }
+
+    fn update<'a>(&'a self, route: &'a Route, info: RatelimitInfo) -> RatelimitFuture<'a> {
+        Box::pin(async move {
+            let mut state = self
+                .state
+                .lock()
+                .expect("Rate limit state shouldn't be poisoned");
+            let now = Instant::now();
+
+            if let Some(hash) = info.bucket {
+                state.bucket_hashes.insert(route.key.clone(), hash);
+            }
+            let bucket_id = state.bucket_id(route);
+
+            match (info.retry_after, info.remaining, info.reset_after) {
+                (Some(retry_after), _, _) if info.global => {
+                    state.global_reset_at = Some(now + retry_after);
+                }
+                (Some(retry_after), _, _) => {
+                    let bucket = Bucket {
+                        remaining: 0,
+                        reset_at: now + retry_after,
+                    };
+                    state.buckets.insert(bucket_id, bucket);
+                }
+                (None, Some(remaining), Some(reset_after)) => {
+                    let bucket = Bucket {
+                        remaining,
+                        reset_at: now + reset_after,
+                    };
+                    state.buckets.insert(bucket_id, bucket);
+                }
+                _ => {}
+            }
+        })
+    }
+}
+
+#[test]
+fn test_route() {
+    let route = Route::new(
+        &Method::DELETE,
+        "/channels/123/messages/456/reactions/%F0%9F%91%8D/@me",
+    );
+    assert_eq!(
+        "DELETE /channels/123/messages/:id/reactions/:reaction/@me",
+        route.key()
+    );
+    assert_eq!("channels/123", route.major_parameter());
+
+    let route = Route::new(&Method::POST, "webhooks/123/token?wait=true");
+    assert_eq!("POST /webhooks/123/token", route.key());
+    assert_eq!("webhooks/123/token", route.major_parameter());
+
+    let route = Route::new(&Method::GET, "/users/@me/guilds/789/member");
+    assert_eq!("GET /users/@me/guilds/:id/member", route.key());
+    assert_eq!("", route.major_parameter());
+    assert!(route.is_global());
+
+    let route = Route::new(&Method::POST, "interactions/123/token/callback");
+    assert_eq!("POST /interactions/123/token/callback", route.key());
+    assert_eq!("interactions/123/token", route.major_parameter());
+    assert!(!route.is_global());
+}
+
+#[test]
+fn test_global_limit() {
+    let ratelimiter = InMemoryRatelimiter::new(1);
+    let channel = Route::new(&Method::GET, "channels/123");
+    let interaction = Route::new(&Method::POST, "interactions/123/token/callback");
+
+    assert_eq!(None, ratelimiter.try_acquire(&channel));
+    assert!(ratelimiter.try_acquire(&channel).is_some());
+    assert_eq!(None, ratelimiter.try_acquire(&interaction));
+    assert_eq!(None, ratelimiter.try_acquire(&interaction));
+}
+
+/// Sends requests to a stand-in API on loopback that rate limits the first one.
+#[tokio::test]
+async fn test_retry_after_429() {
+    use std::time::Instant;
+
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+    };
+    use url::Url;
+
+    use super::Api;
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base_url = Url::parse(&format!("http://{}/api/", listener.local_addr().unwrap())).unwrap();
+
+    let server = tokio::spawn(async move {
+        let response = |status: &str, headers: &str, body: &str| {
+            format!(
+                "HTTP/1.1 {status}\r\nconnection: close\r\ncontent-type: application/json\r\n{headers}content-length: {}\r\n\r\n{body}",
+                body.len()
+            )
+        };
+        let responses = [
+            response(
+                "429 Too Many Requests",
+                "x-ratelimit-bucket: abc\r\nx-ratelimit-scope: user\r\nretry-after: 1\r\n",
+                r#"{"message": "You are being rate limited.", "retry_after": 0.2, "global": false}"#,
+            ),
+            response(
+                "200 OK",
+                "x-ratelimit-bucket: abc\r\nx-ratelimit-limit: 5\r\nx-ratelimit-remaining: 4\r\nx-ratelimit-reset-after: 1.0\r\n",
+                r#"{"url": "wss://gateway.discord.gg"}"#,
+            ),
+        ];
+        let mut paths = vec![];
+        for response in responses {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut request = vec![0; 4096];
+            let length = stream.read(&mut request).await.unwrap();
+            let request = String::from_utf8_lossy(&request[..length]).to_string();
+            paths.push(request.lines().next().unwrap().to_string());
+            assert!(request.contains("user-agent: DiscordBot ("));
+            stream.write_all(response.as_bytes()).await.unwrap();
+            stream.shutdown().await.unwrap();
+        }
+        paths
+    });
+
+    let api = Api::builder().base_url(base_url).build().unwrap();
+    let started_at = Instant::now();
+    let gateway = api.gateway().get_gateway().await.unwrap();
+
+    assert_eq!("wss://gateway.discord.gg/", gateway.url.as_str());
+    assert!(started_at.elapsed() >= Duration::from_millis(200));
+    assert_eq!(
+        vec!["GET /api/v10/gateway HTTP/1.1"; 2],
+        server.await.unwrap()
+    );
+}
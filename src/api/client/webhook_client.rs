@@ -0,0 +1,242 @@
+#[cfg(feature = "debug")]
+use std::fmt;
+use std::sync::Arc;
+
+use serde::Serialize;
+use url::Url;
+
+use crate::api::objects::{
+    channel::{ChannelId, Message, MessageId},
+    webhook::{Webhook, WebhookId},
+};
+
+use super::{
+    endpoints::webhooks::{requests, Webhooks},
+    http_client::HttpClient,
+    ApiBuilder, ApiError, AttachmentFile, Result,
+};
+
+/// Client for a single webhook, authenticated by its token rather than a bot token,
+/// for services that only have the webhook's URL.
+///
+/// ```rust
+/// use datrope::api::client::WebhookClient;
+///
+/// let webhook = WebhookClient::from_url("https://discord.com/api/webhooks/1234/token").unwrap();
+/// ```
+pub struct WebhookClient {
+    webhooks: Webhooks,
+    webhook_id: WebhookId,
+    webhook_token: String,
+}
+
+impl Clone for WebhookClient {
+    fn clone(&self) -> Self {
+        WebhookClient {
+            webhooks: self.webhooks.clone(),
+            webhook_id: WebhookId(self.webhook_id.0.clone()),
+            webhook_token: self.webhook_token.clone(),
+        }
+    }
+}
+
+// The token is left out so it doesn't end up in logs.
+#[cfg(feature = "debug")]
+impl fmt::Debug for WebhookClient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WebhookClient")
+            .field("webhook_id", &self.webhook_id)
+            .finish_non_exhaustive()
+    }
+}
+
+impl WebhookClient {
+    pub(super) fn new(
+        http_client: HttpClient,
+        webhook_id: WebhookId,
+        webhook_token: String,
+    ) -> WebhookClient {
+        WebhookClient {
+            webhooks: Webhooks::new(Arc::new(http_client)),
+            webhook_id,
+            webhook_token,
+        }
+    }
+
+    /// Builds a client with the default `ApiBuilder` settings from a webhook URL
+    /// (eg, `https://discord.com/api/webhooks/{id}/{token}`), using the API at the URL's host.
+    pub fn from_url(url: &str) -> Result<WebhookClient> {
+        let (base_url, webhook_id, webhook_token) = parse_webhook_url(&Url::parse(url)?)?;
+
+        ApiBuilder::new()
+            .base_url(base_url)
+            .build_webhook_client(webhook_id, webhook_token)
+    }
+
+    pub fn webhook_id(&self) -> &WebhookId {
+        &self.webhook_id
+    }
+
+    /// https://discord.com/developers/docs/resources/webhook#get-webhook-with-token
+    pub async fn get_webhook(&self) -> Result<Webhook> {
+        self.webhooks
+            .get_webhook_with_token(&self.webhook_id, &self.webhook_token)
+            .await
+    }
+
+    /// https://discord.com/developers/docs/resources/webhook#modify-webhook-with-token
+    pub async fn modify_webhook(
+        &self,
+        webhook: &requests::ModifyWebhook,
+        reason: Option<&str>,
+    ) -> Result<Webhook> {
+        self.webhooks
+            .modify_webhook_with_token(&self.webhook_id, &self.webhook_token, webhook, reason)
+            .await
+    }
+
+    /// https://discord.com/developers/docs/resources/webhook#delete-webhook-with-token
+    pub async fn delete_webhook(&self, reason: Option<&str>) -> Result<()> {
+        self.webhooks
+            .delete_webhook_with_token(&self.webhook_id, &self.webhook_token, reason)
+            .await
+    }
+
+    /// Sends a message, uploading `files` as attachments.
+    /// Returns the message only when `wait` is set in `query`.
+    ///
+    /// https://discord.com/developers/docs/resources/webhook#execute-webhook
+    pub async fn execute_webhook(
+        &self,
+        message: &requests::ExecuteWebhook,
+        query: &requests::ExecuteWebhookQuery,
+        files: Vec<AttachmentFile>,
+    ) -> Result<Option<Message>> {
+        self.webhooks
+            .execute_webhook(&self.webhook_id, &self.webhook_token, message, query, files)
+            .await
+    }
+
+    /// https://discord.com/developers/docs/resources/webhook#execute-slackcompatible-webhook
+    pub async fn execute_slack_compatible_webhook<B: Serialize + ?Sized>(
+        &self,
+        body: &B,
+        query: &requests::ExecuteCompatibleWebhookQuery,
+    ) -> Result<()> {
+        self.webhooks
+            .execute_slack_compatible_webhook(&self.webhook_id, &self.webhook_token, body, query)
+            .await
+    }
+
+    /// https://discord.com/developers/docs/resources/webhook#execute-githubcompatible-webhook
+    pub async fn execute_github_compatible_webhook<B: Serialize + ?Sized>(
+        &self,
+        event: &str,
+        payload: &B,
+        query: &requests::ExecuteCompatibleWebhookQuery,
+    ) -> Result<()> {
+        self.webhooks
+            .execute_github_compatible_webhook(
+                &self.webhook_id,
+                &self.webhook_token,
+                event,
+                payload,
+                query,
+            )
+            .await
+    }
+
+    /// https://discord.com/developers/docs/resources/webhook#get-webhook-message
+    pub async fn get_webhook_message(
+        &self,
+        message_id: &MessageId,
+        thread_id: Option<&ChannelId>,
+    ) -> Result<Message> {
+        self.webhooks
+            .get_webhook_message(&self.webhook_id, &self.webhook_token, message_id, thread_id)
+            .await
+    }
+
+    /// https://discord.com/developers/docs/resources/webhook#edit-webhook-message
+    pub async fn edit_webhook_message(
+        &self,
+        message_id: &MessageId,
+        thread_id: Option<&ChannelId>,
+        message: &requests::EditWebhookMessage,
+        files: Vec<AttachmentFile>,
+    ) -> Result<Message> {
+        self.webhooks
+            .edit_webhook_message(
+                &self.webhook_id,
+                &self.webhook_token,
+                message_id,
+                thread_id,
+                message,
+                files,
+            )
+            .await
+    }
+
+    /// https://discord.com/developers/docs/resources/webhook#delete-webhook-message
+    pub async fn delete_webhook_message(
+        &self,
+        message_id: &MessageId,
+        thread_id: Option<&ChannelId>,
+    ) -> Result<()> {
+        self.webhooks
+            .delete_webhook_message(&self.webhook_id, &self.webhook_token, message_id, thread_id)
+            .await
+    }
+}
+
+/// Splits a webhook URL into the API's base URL (without the version), ID, and token.
+fn parse_webhook_url(url: &Url) -> Result<(Url, WebhookId, String)> {
+    let segments: Vec<&str> = url
+        .path_segments()
+        .ok_or(ApiError::InvalidWebhookUrl)?
+        .filter(|segment| !segment.is_empty())
+        .collect();
+    let [prefix @ .., "webhooks", id, token] = segments.as_slice() else {
+        return Err(ApiError::InvalidWebhookUrl);
+    };
+
+    // The version comes from the builder instead (eg, `/api/v10/webhooks/...`).
+    let prefix = match prefix {
+        [rest @ .., version]
+            if version.len() > 1
+                && version.starts_with('v')
+                && version[1..].chars().all(|c| c.is_ascii_digit()) =>
+        {
+            rest
+        }
+        prefix => prefix,
+    };
+
+    let mut base_url = url.clone();
+    base_url.set_query(None);
+    base_url.set_fragment(None);
+    base_url.set_path(&format!("{}/", prefix.join("/")));
+
+    Ok((base_url, WebhookId(id.to_string()), token.to_string()))
+}
+
+#[test]
+fn test_parse_webhook_url() {
+    let (base_url, id, token) = parse_webhook_url(
+        &Url::parse("https://discord.com/api/webhooks/1234/abc-DEF_123").unwrap(),
+    )
+    .unwrap();
+    assert_eq!("https://discord.com/api/", base_url.as_str());
+    assert_eq!("1234", id.0);
+    assert_eq!("abc-DEF_123", token);
+
+    let (base_url, ..) = parse_webhook_url(
+        &Url::parse("https://canary.discord.com/api/v10/webhooks/1234/token?wait=true").unwrap(),
+    )
+    .unwrap();
+    assert_eq!("https://canary.discord.com/api/", base_url.as_str());
+
+    assert!(
+        parse_webhook_url(&Url::parse("https://discord.com/api/webhooks/1234").unwrap()).is_err()
+    );
+}
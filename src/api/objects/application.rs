@@ -2,6 +2,8 @@ use crate::flags;
 use enumset::EnumSetType;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "serde")]
+use serde_repr::{Deserialize_repr, Serialize_repr};
 use url::Url;
 
 use super::{
@@ -69,8 +71,8 @@ pub struct Application {
 
 #[cfg_attr(feature = "clone", derive(Clone))]
 #[cfg_attr(feature = "debug", derive(Debug))]
-#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "SCREAMING_SNAKE_CASE"))]
+#[cfg_attr(feature = "serde", derive(Deserialize_repr, Serialize_repr))]
+#[cfg_attr(feature = "serde", repr(u8))]
 pub enum IntegrationType {
     GuildInstall = 0,
     UserInstall = 1,
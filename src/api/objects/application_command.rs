@@ -1,5 +1,7 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "serde")]
+use serde_repr::{Deserialize_repr, Serialize_repr};
 
 use super::{
     application::{ApplicationId, IntegrationType},
@@ -36,10 +38,12 @@ pub struct ApplicationCommand {
     pub default_permission: Option<bool>,
     #[cfg_attr(feature = "serde", serde(default))]
     pub nsfw: Option<bool>,
+    /// Installation contexts where the command is available, only for global commands.
     #[cfg_attr(feature = "serde", serde(default))]
-    pub integration_types: Option<IntegrationType>,
+    pub integration_types: Vec<IntegrationType>,
+    /// Interaction contexts where the command can be used, only for global commands.
     #[cfg_attr(feature = "serde", serde(default))]
-    pub contexts: Vec<InteractionContextType>,
+    pub contexts: Option<Vec<InteractionContextType>>,
     pub version: Version,
 }
 
@@ -66,7 +70,8 @@ pub struct Version(pub String);
 /// Discord docs: https://discord.com/developers/docs/interactions/application-commands#application-command-object-application-command-types
 #[cfg_attr(feature = "clone", derive(Clone))]
 #[cfg_attr(feature = "debug", derive(Debug))]
-#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "serde", derive(Deserialize_repr, Serialize_repr))]
+#[cfg_attr(feature = "serde", repr(u8))]
 pub enum ApplicationCommandType {
     ChatInput = 1,
     User = 2,
@@ -80,34 +85,98 @@ pub enum ApplicationCommandType {
 pub struct ApplicationCommandOption {
     pub r#type: ApplicationCommandOptionType,
     pub name: OptionName,
-    #[cfg_attr(feature = "serde", serde(default))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub name_localizations: Option<Translation<OptionName>>,
     pub description: OptionDescription,
-    #[cfg_attr(feature = "serde", serde(default))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub description_localizations: Option<Translation<OptionDescription>>,
-    #[cfg_attr(feature = "serde", serde(default))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub required: Option<bool>,
-    #[cfg_attr(feature = "serde", serde(default))]
+    /// Only for `String`, `Integer`, and `Number` options, up to 25.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub choices: Vec<ApplicationCommandOptionChoice>,
-    #[cfg_attr(feature = "serde", serde(default))]
-    pub options: Vec<ApplicationCommandOptionChoice>,
-    #[cfg_attr(feature = "serde", serde(default))]
+    /// Only for `SubCommand` and `SubCommandGroup` options.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub options: Vec<ApplicationCommandOption>,
+    /// Only for `Channel` options.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub channel_types: Vec<ChannelType>,
-    #[cfg_attr(feature = "serde", serde(default))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub min_value: Option<Value>,
-    #[cfg_attr(feature = "serde", serde(default))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub max_value: Option<Value>,
-    #[cfg_attr(feature = "serde", serde(default))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub min_length: Option<u16>,
-    #[cfg_attr(feature = "serde", serde(default))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub max_length: Option<u16>,
-    #[cfg_attr(feature = "serde", serde(default))]
+    /// Can't be used with `choices`.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub autocomplete: Option<bool>,
 }
 
+impl ApplicationCommandOption {
+    /// Option with the given type, name, and description, and everything else left unset.
+    pub fn new(
+        r#type: ApplicationCommandOptionType,
+        name: OptionName,
+        description: OptionDescription,
+    ) -> Self {
+        ApplicationCommandOption {
+            r#type,
+            name,
+            name_localizations: None,
+            description,
+            description_localizations: None,
+            required: None,
+            choices: vec![],
+            options: vec![],
+            channel_types: vec![],
+            min_value: None,
+            max_value: None,
+            min_length: None,
+            max_length: None,
+            autocomplete: None,
+        }
+    }
+}
+
 #[cfg_attr(feature = "clone", derive(Clone))]
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "serde", serde(untagged))]
 pub enum Value {
     Integer(i64),
     Number(f64),
@@ -126,8 +195,8 @@ pub struct OptionDescription(pub String);
 /// Discord docs: https://discord.com/developers/docs/interactions/application-commands#application-command-object-application-command-option-type
 #[cfg_attr(feature = "clone", derive(Clone))]
 #[cfg_attr(feature = "debug", derive(Debug))]
-#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "SCREAMING_SNAKE_CASE"))]
+#[cfg_attr(feature = "serde", derive(Deserialize_repr, Serialize_repr))]
+#[cfg_attr(feature = "serde", repr(u8))]
 pub enum ApplicationCommandOptionType {
     SubCommand = 1,
     SubCommandGroup = 2,
@@ -147,7 +216,10 @@ pub enum ApplicationCommandOptionType {
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub struct ApplicationCommandOptionChoice {
     pub name: ChoiceName,
-    #[cfg_attr(feature = "serde", serde(default))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub name_localizations: Option<Translation<ChoiceName>>,
     pub value: ChoiceValue,
 }
@@ -160,8 +232,44 @@ pub struct ChoiceName(pub String);
 #[cfg_attr(feature = "clone", derive(Clone))]
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "serde", serde(untagged))]
 pub enum ChoiceValue {
     String(String),
     Integer(i64),
     Double(f64),
 }
+
+/// Discord docs: https://discord.com/developers/docs/interactions/application-commands#application-command-permissions-object-guild-application-command-permissions-structure
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct GuildApplicationCommandPermissions {
+    /// ID of the command, or the ID of the application if the permissions apply to all commands.
+    pub id: String,
+    pub application_id: ApplicationId,
+    pub guild_id: GuildId,
+    pub permissions: Vec<ApplicationCommandPermission>,
+}
+
+/// Discord docs: https://discord.com/developers/docs/interactions/application-commands#application-command-permissions-object-application-command-permissions-structure
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct ApplicationCommandPermission {
+    /// ID of the role, user, or channel. Can also be a permission constant
+    /// (`guild_id` for `@everyone`, `guild_id - 1` for all channels).
+    pub id: String,
+    pub r#type: ApplicationCommandPermissionType,
+    pub permission: bool,
+}
+
+/// Discord docs: https://discord.com/developers/docs/interactions/application-commands#application-command-permissions-object-application-command-permission-type
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize_repr, Serialize_repr))]
+#[cfg_attr(feature = "serde", repr(u8))]
+pub enum ApplicationCommandPermissionType {
+    Role = 1,
+    User = 2,
+    Channel = 3,
+}
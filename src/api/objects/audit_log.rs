@@ -6,8 +6,10 @@ use serde::{Deserialize, Serialize};
 #[cfg(feature = "serde")]
 use serde_json::Value;
 
+use crate::codes;
+
 use super::{
-    application::{ApplicationId, IntegrationType},
+    application::ApplicationId,
     application_command::ApplicationCommand,
     auto_moderation::AutoModerationRule,
     channel::{Channel, ChannelId, MessageId},
@@ -47,7 +49,7 @@ pub struct AuditLogEntry {
     #[cfg_attr(feature = "serde", serde(default))]
     pub options: Option<OptionalAuditEntryInfo>,
     #[cfg_attr(feature = "serde", serde(default))]
-    pub reason: String,
+    pub reason: Option<String>,
 }
 
 #[cfg_attr(feature = "clone", derive(Clone))]
@@ -55,101 +57,123 @@ pub struct AuditLogEntry {
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub struct AuditLogEntryId(pub String);
 
-/// Discord docs: https://discord.com/developers/docs/resources/audit-log#audit-log-entry-object-audit-log-events
-#[cfg_attr(feature = "clone", derive(Clone))]
-#[cfg_attr(feature = "debug", derive(Debug))]
-#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
-pub enum AuditLogEvent {
-    GuildUpdate = 1,
-    ChannelCreate = 10,
-    ChannelUpdate = 11,
-    ChannelDelete = 12,
-    ChannelOverwriteCreate = 13,
-    ChannelOverwriteUpdate = 14,
-    ChannelOverwriteDelete = 15,
-    MemberKick = 20,
-    MemberPrune = 21,
-    MemberBanAdd = 22,
-    MemberBanRemove = 23,
-    MemberUpdate = 24,
-    MemberRoleUpdate = 25,
-    MemberMove = 26,
-    MemberDisconnect = 27,
-    BotAdd = 28,
-    RoleCreate = 30,
-    RoleUpdate = 31,
-    RoleDelete = 32,
-    InviteCreate = 40,
-    InviteUpdate = 41,
-    InviteDelete = 42,
-    WebhookCreate = 50,
-    WebhookUpdate = 51,
-    WebhookDelete = 52,
-    EmojiCreate = 60,
-    EmojiUpdate = 61,
-    EmojiDelete = 62,
-    MessageDelete = 72,
-    MessageBulkDelete = 73,
-    MessagePin = 74,
-    MessageUnpin = 75,
-    IntegrationCreate = 80,
-    IntegrationUpdate = 81,
-    IntegrationDelete = 82,
-    StageInstanceCreate = 83,
-    StageInstanceUpdate = 84,
-    StageInstanceDelete = 85,
-    StickerCreate = 90,
-    StickerUpdate = 91,
-    StickerDelete = 92,
-    GuildScheduledEventCreate = 100,
-    GuildScheduledEventUpdate = 101,
-    GuildScheduledEventDelete = 102,
-    ThreadCreate = 110,
-    ThreadUpdate = 111,
-    ThreadDelete = 112,
-    ApplicationCommandPermissionUpdate = 121,
-    AutoModerationRuleCreate = 140,
-    AutoModerationRuleUpdate = 141,
-    AutoModerationRuleDelete = 142,
-    AutoModerationBlockMessage = 143,
-    AutoModerationFlagToChannel = 144,
-    AutoModerationUserCommunicationDisabled = 145,
-    CreatorMonetizationRequestCreated = 150,
-    CreatorMonetizationTermsAccepted = 151,
-    OnboardingPromptCreate = 163,
-    OnboardingPromptUpdate = 164,
-    OnboardingPromptDelete = 165,
-    OnboardingCreate = 166,
-    OnboardingUpdate = 167,
-    HomeSettingsCreate = 190,
-    HomeSettingsUpdate = 191,
+codes! {
+    /// Discord docs: https://discord.com/developers/docs/resources/audit-log#audit-log-entry-object-audit-log-events
+    #[cfg_attr(feature = "clone", derive(Clone))]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    pub enum AuditLogEvent: u16 {
+        GuildUpdate = 1,
+        ChannelCreate = 10,
+        ChannelUpdate = 11,
+        ChannelDelete = 12,
+        ChannelOverwriteCreate = 13,
+        ChannelOverwriteUpdate = 14,
+        ChannelOverwriteDelete = 15,
+        MemberKick = 20,
+        MemberPrune = 21,
+        MemberBanAdd = 22,
+        MemberBanRemove = 23,
+        MemberUpdate = 24,
+        MemberRoleUpdate = 25,
+        MemberMove = 26,
+        MemberDisconnect = 27,
+        BotAdd = 28,
+        RoleCreate = 30,
+        RoleUpdate = 31,
+        RoleDelete = 32,
+        InviteCreate = 40,
+        InviteUpdate = 41,
+        InviteDelete = 42,
+        WebhookCreate = 50,
+        WebhookUpdate = 51,
+        WebhookDelete = 52,
+        EmojiCreate = 60,
+        EmojiUpdate = 61,
+        EmojiDelete = 62,
+        MessageDelete = 72,
+        MessageBulkDelete = 73,
+        MessagePin = 74,
+        MessageUnpin = 75,
+        IntegrationCreate = 80,
+        IntegrationUpdate = 81,
+        IntegrationDelete = 82,
+        StageInstanceCreate = 83,
+        StageInstanceUpdate = 84,
+        StageInstanceDelete = 85,
+        StickerCreate = 90,
+        StickerUpdate = 91,
+        StickerDelete = 92,
+        GuildScheduledEventCreate = 100,
+        GuildScheduledEventUpdate = 101,
+        GuildScheduledEventDelete = 102,
+        ThreadCreate = 110,
+        ThreadUpdate = 111,
+        ThreadDelete = 112,
+        ApplicationCommandPermissionUpdate = 121,
+        SoundboardSoundCreate = 130,
+        SoundboardSoundUpdate = 131,
+        SoundboardSoundDelete = 132,
+        AutoModerationRuleCreate = 140,
+        AutoModerationRuleUpdate = 141,
+        AutoModerationRuleDelete = 142,
+        AutoModerationBlockMessage = 143,
+        AutoModerationFlagToChannel = 144,
+        AutoModerationUserCommunicationDisabled = 145,
+        AutoModerationQuarantineUser = 146,
+        CreatorMonetizationRequestCreated = 150,
+        CreatorMonetizationTermsAccepted = 151,
+        OnboardingPromptCreate = 163,
+        OnboardingPromptUpdate = 164,
+        OnboardingPromptDelete = 165,
+        OnboardingCreate = 166,
+        OnboardingUpdate = 167,
+        HomeSettingsCreate = 190,
+        HomeSettingsUpdate = 191,
+        GuildScheduledEventExceptionCreate = 200,
+        GuildScheduledEventExceptionUpdate = 201,
+        GuildScheduledEventExceptionDelete = 202,
+    }
 }
 
+/// Which fields are set depends on the entry's `action_type`.
+///
 /// Discord docs: https://discord.com/developers/docs/resources/audit-log#audit-log-entry-object-optional-audit-entry-info
 #[cfg_attr(feature = "clone", derive(Clone))]
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub struct OptionalAuditEntryInfo {
-    pub application_id: ApplicationId,
-    pub auto_moderation_rule_name: String,
-    pub auto_moderation_rule_trigger_type: String,
-    pub channel_id: ChannelId,
-    pub count: u64,
-    pub delete_member_days: u64,
-    pub id: String,
-    pub members_removed: u64,
-    pub message_id: MessageId,
+    /// Application whose command permissions were updated.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub application_id: Option<ApplicationId>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub auto_moderation_rule_name: Option<String>,
+    /// Trigger type of the rule, as a string (eg, `"1"`).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub auto_moderation_rule_trigger_type: Option<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub channel_id: Option<ChannelId>,
+    /// Number of entities affected, as a string.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub count: Option<String>,
+    /// Days of inactivity for pruned members, as a string.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub delete_member_days: Option<String>,
+    /// ID of the overwritten role or member.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub id: Option<String>,
+    /// Number of pruned members, as a string.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub members_removed: Option<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub message_id: Option<MessageId>,
+    #[cfg_attr(feature = "serde", serde(default))]
     pub role_name: Option<String>,
-    pub r#type: OverwrittenType,
-    pub integration_type: IntegrationType,
-}
-
-#[cfg_attr(feature = "clone", derive(Clone))]
-#[cfg_attr(feature = "debug", derive(Debug))]
-#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
-pub enum OverwrittenType {
-    Role = 0,
-    Member = 1,
+    /// Type of the overwritten entity: `"0"` for roles and `"1"` for members.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub r#type: Option<String>,
+    /// Type of the integration that added or removed the member (eg, `twitch`).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub integration_type: Option<String>,
 }
 
 /// Discord docs: https://discord.com/developers/docs/resources/audit-log#audit-log-change-object
@@ -160,12 +184,50 @@ pub struct AuditLogChange {
     #[cfg(not(feature = "serde"))]
     pub new_value: Option<Rc<dyn Any>>,
     #[cfg(feature = "serde")]
-    new_value: Option<Value>,
+    pub new_value: Option<Value>,
 
     #[cfg(not(feature = "serde"))]
     pub old_value: Option<Rc<dyn Any>>,
     #[cfg(feature = "serde")]
-    old_value: Option<Value>,
+    pub old_value: Option<Value>,
 
     pub key: String,
 }
+
+#[cfg(feature = "serde")]
+#[test]
+fn audit_log_entry() {
+    let entry: AuditLogEntry = serde_json::from_str(
+        r#"{
+            "id": "1234",
+            "user_id": "5678",
+            "target_id": null,
+            "action_type": 73,
+            "options": {"channel_id": "91011", "count": "12"}
+        }"#,
+    )
+    .unwrap();
+
+    assert!(matches!(
+        entry.action_type,
+        AuditLogEvent::MessageBulkDelete
+    ));
+    assert_eq!(Some("12"), entry.options.unwrap().count.as_deref());
+    assert!(entry.reason.is_none());
+
+    let entry: AuditLogEntry = serde_json::from_str(
+        r#"{"id": "1235", "user_id": "5678", "target_id": "91011", "action_type": 146}"#,
+    )
+    .unwrap();
+    assert!(matches!(
+        entry.action_type,
+        AuditLogEvent::AutoModerationQuarantineUser
+    ));
+
+    let entry: AuditLogEntry = serde_json::from_str(
+        r#"{"id": "1236", "user_id": null, "target_id": null, "action_type": 1000}"#,
+    )
+    .unwrap();
+    assert!(matches!(entry.action_type, AuditLogEvent::Unknown(1000)));
+    assert_eq!("1000", serde_json::to_string(&entry.action_type).unwrap());
+}
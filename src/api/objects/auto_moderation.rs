@@ -1,5 +1,7 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "serde")]
+use serde_repr::{Deserialize_repr, Serialize_repr};
 
 use super::{channel::ChannelId, guild::GuildId, permissions::RoleId};
 
@@ -34,32 +36,70 @@ pub struct CreatorId(pub String);
 /// Discord docs: https://discord.com/developers/docs/resources/auto-moderation#auto-moderation-rule-object-trigger-types
 #[cfg_attr(feature = "clone", derive(Clone))]
 #[cfg_attr(feature = "debug", derive(Debug))]
-#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "SCREAMING_SNAKE_CASE"))]
+#[cfg_attr(feature = "serde", derive(Deserialize_repr, Serialize_repr))]
+#[cfg_attr(feature = "serde", repr(u8))]
 pub enum TriggerType {
     Keyword = 1,
     Spam = 3,
     KeywordPreset = 4,
     MentionSpam = 5,
+    MemberProfile = 6,
 }
 
+/// Which fields are used depends on the rule's trigger type.
+///
+/// Discord docs: https://discord.com/developers/docs/resources/auto-moderation#auto-moderation-rule-object-trigger-metadata
+#[derive(Default)]
 #[cfg_attr(feature = "clone", derive(Clone))]
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub struct TriggerMetadata {
+    /// Up to 1000 substrings (with `*` wildcards) of up to 60 characters each,
+    /// for `Keyword` and `MemberProfile` rules.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub keyword_filter: Vec<String>,
+    /// Up to 10 Rust-flavored regular expressions of up to 260 characters each,
+    /// for `Keyword` and `MemberProfile` rules.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub regex_patterns: Vec<String>,
+    /// For `KeywordPreset` rules.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub presets: Vec<KeywordPresetType>,
+    /// Substrings exempt from the rule, of up to 60 characters each. Up to 100 for `Keyword`
+    /// and `MemberProfile` rules, and up to 1000 for `KeywordPreset` rules.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub allow_list: Vec<String>,
-    pub mention_total_limit: u8,
-    pub mention_raid_protection_enabled: bool,
+    /// Up to 50 unique role and user mentions per message, for `MentionSpam` rules.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub mention_total_limit: Option<u8>,
+    /// For `MentionSpam` rules.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub mention_raid_protection_enabled: Option<bool>,
 }
 
 /// Discord docs: https://discord.com/developers/docs/resources/auto-moderation#auto-moderation-rule-object-keyword-preset-types
 #[cfg_attr(feature = "clone", derive(Clone))]
 #[cfg_attr(feature = "debug", derive(Debug))]
-#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "SCREAMING_SNAKE_CASE"))]
+#[cfg_attr(feature = "serde", derive(Deserialize_repr, Serialize_repr))]
+#[cfg_attr(feature = "serde", repr(u8))]
 pub enum KeywordPresetType {
     Profanity = 1,
     SexualContent = 2,
@@ -69,10 +109,11 @@ pub enum KeywordPresetType {
 /// Discord docs: https://discord.com/developers/docs/resources/auto-moderation#auto-moderation-rule-object-event-types
 #[cfg_attr(feature = "clone", derive(Clone))]
 #[cfg_attr(feature = "debug", derive(Debug))]
-#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "SCREAMING_SNAKE_CASE"))]
+#[cfg_attr(feature = "serde", derive(Deserialize_repr, Serialize_repr))]
+#[cfg_attr(feature = "serde", repr(u8))]
 pub enum EventType {
     MessageSend = 1,
+    MemberUpdate = 2,
 }
 
 /// Discord docs: https://discord.com/developers/docs/resources/auto-moderation#auto-moderation-action-object-auto-moderation-action-structure
@@ -81,27 +122,48 @@ pub enum EventType {
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub struct AutoModerationAction {
     pub r#type: ActionType,
-    #[cfg_attr(feature = "serde", serde(default))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub metadata: Option<ActionMetadata>,
 }
 
 /// Discord docs: https://discord.com/developers/docs/resources/auto-moderation#auto-moderation-action-object-action-types
 #[cfg_attr(feature = "clone", derive(Clone))]
 #[cfg_attr(feature = "debug", derive(Debug))]
-#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "SCREAMING_SNAKE_CASE"))]
+#[cfg_attr(feature = "serde", derive(Deserialize_repr, Serialize_repr))]
+#[cfg_attr(feature = "serde", repr(u8))]
 pub enum ActionType {
     BlockMessage = 1,
     SendAlertMessage = 2,
     Timeout = 3,
+    BlockMemberInteraction = 4,
 }
 
+/// Discord docs: https://discord.com/developers/docs/resources/auto-moderation#auto-moderation-action-object-action-metadata
+#[derive(Default)]
 #[cfg_attr(feature = "clone", derive(Clone))]
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub struct ActionMetadata {
-    pub channel_id: ChannelId,
-    pub duration_seconds: u32,
-    #[cfg_attr(feature = "serde", serde(default))]
+    /// Channel to send alerts to, for `SendAlertMessage` actions.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub channel_id: Option<ChannelId>,
+    /// Up to 2419200 (4 weeks), for `Timeout` actions.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub duration_seconds: Option<u32>,
+    /// Up to 150 characters shown to members whose message was blocked,
+    /// for `BlockMessage` actions.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub custom_message: Option<String>,
 }
@@ -1,11 +1,111 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "serde")]
+use serde_repr::{Deserialize_repr, Serialize_repr};
+#[cfg(feature = "serde")]
+use time::serde::iso8601;
+use time::OffsetDateTime;
+
+use crate::flags;
+#[cfg(feature = "serde")]
+use crate::timestamp::rfc3339;
 
-/// Discord docs: https://discord.com/developers/docs/resources/channel#channels-resource
+use super::{
+    application::ApplicationId,
+    emoji::{Emoji, EmojiId},
+    guild::{GuildId, GuildMember},
+    message_components::Component,
+    permissions::{Permissions, RoleId},
+    poll::Poll,
+    sticker::StickerItem,
+    user::{User, UserId},
+    webhook::WebhookId,
+    ImageHash,
+};
+
+/// Discord docs: https://discord.com/developers/docs/resources/channel#channel-object
 #[cfg_attr(feature = "clone", derive(Clone))]
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
-pub struct Channel {}
+pub struct Channel {
+    pub id: ChannelId,
+    pub r#type: ChannelType,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub guild_id: Option<GuildId>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub position: Option<i32>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub permission_overwrites: Vec<Overwrite>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub name: Option<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub topic: Option<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub nsfw: bool,
+    /// May not point to an existing or valid message.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub last_message_id: Option<MessageId>,
+    /// Bits per second, for voice channels.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub bitrate: Option<u32>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub user_limit: Option<u32>,
+    /// Seconds a user has to wait before sending another message.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub rate_limit_per_user: Option<u32>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub recipients: Vec<User>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub icon: Option<ImageHash>,
+    /// Creator of the group DM or thread.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub owner_id: Option<UserId>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub application_id: Option<ApplicationId>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub managed: Option<bool>,
+    /// Category for guild channels, or the channel a thread was created in.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub parent_id: Option<ChannelId>,
+    #[cfg_attr(feature = "serde", serde(default, with = "iso8601::option"))]
+    pub last_pin_timestamp: Option<OffsetDateTime>,
+    /// Voice region, automatic when `None`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub rtc_region: Option<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub video_quality_mode: Option<VideoQualityMode>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub message_count: Option<u32>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub member_count: Option<u32>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub thread_metadata: Option<ThreadMetadata>,
+    /// Thread member object for the current user, if they've joined the thread.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub member: Option<ThreadMember>,
+    /// Minutes.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub default_auto_archive_duration: Option<u32>,
+    /// Permissions of the user or interaction in the channel, including overwrites.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub permissions: Option<Permissions>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub flags: Option<ChannelFlags>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub total_message_sent: Option<u32>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub available_tags: Vec<ForumTag>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub applied_tags: Vec<ForumTagId>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub default_reaction_emoji: Option<DefaultReaction>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub default_thread_rate_limit_per_user: Option<u32>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub default_sort_order: Option<SortOrderType>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub default_forum_layout: Option<ForumLayoutType>,
+}
 
 #[cfg_attr(feature = "clone", derive(Clone))]
 #[cfg_attr(feature = "debug", derive(Debug))]
@@ -15,8 +115,8 @@ pub struct ChannelId(pub String);
 /// Discord docs: https://discord.com/developers/docs/resources/channel#channel-object-channel-types
 #[cfg_attr(feature = "clone", derive(Clone))]
 #[cfg_attr(feature = "debug", derive(Debug))]
-#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "SCREAMING_SNAKE_CASE"))]
+#[cfg_attr(feature = "serde", derive(Deserialize_repr, Serialize_repr))]
+#[cfg_attr(feature = "serde", repr(u8))]
 pub enum ChannelType {
     GuildText = 0,
     Dm = 1,
@@ -33,17 +133,176 @@ pub enum ChannelType {
     GuildMedia = 16,
 }
 
+/// Discord docs: https://discord.com/developers/docs/resources/channel#channel-object-video-quality-modes
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize_repr, Serialize_repr))]
+#[cfg_attr(feature = "serde", repr(u8))]
+pub enum VideoQualityMode {
+    Auto = 1,
+    /// 720p.
+    Full = 2,
+}
+
+flags!(channel_flags: u32 {
+    Pinned = 1 << 1,
+    RequireTag = 1 << 4,
+    HideMediaDownloadOptions = 1 << 15,
+});
+pub use channel_flags::Flags as ChannelFlags;
+
+/// Discord docs: https://discord.com/developers/docs/resources/channel#channel-object-sort-order-types
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize_repr, Serialize_repr))]
+#[cfg_attr(feature = "serde", repr(u8))]
+pub enum SortOrderType {
+    LatestActivity = 0,
+    CreationDate = 1,
+}
+
+/// Discord docs: https://discord.com/developers/docs/resources/channel#channel-object-forum-layout-types
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize_repr, Serialize_repr))]
+#[cfg_attr(feature = "serde", repr(u8))]
+pub enum ForumLayoutType {
+    NotSet = 0,
+    ListView = 1,
+    GalleryView = 2,
+}
+
 /// Discord docs: https://discord.com/developers/docs/resources/channel#message-object
 #[cfg_attr(feature = "clone", derive(Clone))]
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
-pub struct Message {}
+pub struct Message {
+    pub id: MessageId,
+    pub channel_id: ChannelId,
+    /// Only set for messages received through the gateway.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub guild_id: Option<GuildId>,
+    /// Not a real user for messages sent by webhooks (see `webhook_id`).
+    pub author: User,
+    /// Empty without the `MESSAGE_CONTENT` intent, unless the message mentions the bot or is a DM.
+    pub content: String,
+    #[cfg_attr(feature = "serde", serde(with = "iso8601"))]
+    pub timestamp: OffsetDateTime,
+    #[cfg_attr(feature = "serde", serde(with = "iso8601::option"))]
+    pub edited_timestamp: Option<OffsetDateTime>,
+    pub tts: bool,
+    pub mention_everyone: bool,
+    pub mentions: Vec<User>,
+    pub mention_roles: Vec<RoleId>,
+    pub attachments: Vec<Attachment>,
+    pub embeds: Vec<Embed>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub reactions: Vec<ReactionObject>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub nonce: Option<Nonce>,
+    pub pinned: bool,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub webhook_id: Option<WebhookId>,
+    pub r#type: MessageType,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub application_id: Option<ApplicationId>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub flags: Option<MessageFlags>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub message_reference: Option<MessageReference>,
+    /// Message that was replied to. `None` when it has been deleted,
+    /// or when Discord didn't include it.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub referenced_message: Option<Box<Message>>,
+    /// Thread started from the message.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub thread: Option<Box<Channel>>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub components: Vec<Component>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub sticker_items: Vec<StickerItem>,
+    /// Approximate position of the message in a thread.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub position: Option<u32>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub poll: Option<Poll>,
+}
 
 #[cfg_attr(feature = "clone", derive(Clone))]
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub struct MessageId(pub String);
 
+/// Used to check whether a message was sent.
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "serde", serde(untagged))]
+pub enum Nonce {
+    Integer(i64),
+    String(String),
+}
+
+/// Discord docs: https://discord.com/developers/docs/resources/channel#message-object-message-types
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize_repr, Serialize_repr))]
+#[cfg_attr(feature = "serde", repr(u8))]
+pub enum MessageType {
+    Default = 0,
+    RecipientAdd = 1,
+    RecipientRemove = 2,
+    Call = 3,
+    ChannelNameChange = 4,
+    ChannelIconChange = 5,
+    ChannelPinnedMessage = 6,
+    UserJoin = 7,
+    GuildBoost = 8,
+    GuildBoostTier1 = 9,
+    GuildBoostTier2 = 10,
+    GuildBoostTier3 = 11,
+    ChannelFollowAdd = 12,
+    GuildDiscoveryDisqualified = 14,
+    GuildDiscoveryRequalified = 15,
+    GuildDiscoveryGracePeriodInitialWarning = 16,
+    GuildDiscoveryGracePeriodFinalWarning = 17,
+    ThreadCreated = 18,
+    Reply = 19,
+    ChatInputCommand = 20,
+    ThreadStarterMessage = 21,
+    GuildInviteReminder = 22,
+    ContextMenuCommand = 23,
+    AutoModerationAction = 24,
+    RoleSubscriptionPurchase = 25,
+    InteractionPremiumUpsell = 26,
+    StageStart = 27,
+    StageEnd = 28,
+    StageSpeaker = 29,
+    StageTopic = 31,
+    GuildApplicationPremiumSubscription = 32,
+    GuildIncidentAlertModeEnabled = 36,
+    GuildIncidentAlertModeDisabled = 37,
+    GuildIncidentReportRaid = 38,
+    GuildIncidentReportFalseAlarm = 39,
+    PurchaseNotification = 44,
+    PollResult = 46,
+}
+
+flags!(message_flags: u32 {
+    Crossposted = 1 << 0,
+    IsCrosspost = 1 << 1,
+    SuppressEmbeds = 1 << 2,
+    SourceMessageDeleted = 1 << 3,
+    Urgent = 1 << 4,
+    HasThread = 1 << 5,
+    Ephemeral = 1 << 6,
+    Loading = 1 << 7,
+    FailedToMentionSomeRolesInThread = 1 << 8,
+    SuppressNotifications = 1 << 12,
+    IsVoiceMessage = 1 << 13,
+});
+pub use message_flags::Flags as MessageFlags;
+
 /// Discord docs: https://discord.com/developers/docs/resources/channel#message-interaction-metadata-object
 #[cfg_attr(feature = "clone", derive(Clone))]
 #[cfg_attr(feature = "debug", derive(Debug))]
@@ -57,76 +316,457 @@ pub struct MessageInteractionMetadata {}
 pub struct MessageCall {}
 
 /// Discord docs: https://discord.com/developers/docs/resources/channel#message-reference-object
+#[derive(Default)]
 #[cfg_attr(feature = "clone", derive(Clone))]
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
-pub struct MessageReference {}
+pub struct MessageReference {
+    /// Defaults to `Default` (a reply) when sending.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub r#type: Option<MessageReferenceType>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub message_id: Option<MessageId>,
+    /// Optional when sending, as it defaults to the channel the message is sent in.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub channel_id: Option<ChannelId>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub guild_id: Option<GuildId>,
+    /// Whether to error when the referenced message doesn't exist, rather than
+    /// sending a normal message. Defaults to `true`. Only used when sending.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub fail_if_not_exists: Option<bool>,
+}
+
+impl MessageReference {
+    /// Reference for replying to a message in the same channel.
+    pub fn reply(message_id: MessageId) -> Self {
+        MessageReference {
+            message_id: Some(message_id),
+            ..Default::default()
+        }
+    }
+}
+
+/// Discord docs: https://discord.com/developers/docs/resources/channel#message-reference-types
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize_repr, Serialize_repr))]
+#[cfg_attr(feature = "serde", repr(u8))]
+pub enum MessageReferenceType {
+    Default = 0,
+    Forward = 1,
+}
 
 /// Discord docs: https://discord.com/developers/docs/resources/channel#followed-channel-object
 #[cfg_attr(feature = "clone", derive(Clone))]
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
-pub struct FollowedChannel {}
+pub struct FollowedChannel {
+    pub channel_id: ChannelId,
+    pub webhook_id: WebhookId,
+}
 
 /// Discord docs: https://discord.com/developers/docs/resources/channel#reaction-object
 #[cfg_attr(feature = "clone", derive(Clone))]
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
-pub struct ReactionObject {}
+pub struct ReactionObject {
+    /// Total, including super reactions.
+    pub count: u32,
+    pub count_details: ReactionCountDetails,
+    /// Whether the current user reacted with this emoji.
+    pub me: bool,
+    pub me_burst: bool,
+    /// Only `id`, `name`, and `animated` are set.
+    pub emoji: Emoji,
+    /// Hex colors used for super reactions.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub burst_colors: Vec<String>,
+}
 
 /// Discord docs: https://discord.com/developers/docs/resources/channel#reaction-count-details-object
 #[cfg_attr(feature = "clone", derive(Clone))]
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
-pub struct ReactionCountDetails {}
+pub struct ReactionCountDetails {
+    /// Super reactions.
+    pub burst: u32,
+    pub normal: u32,
+}
 
 /// Discord docs: https://discord.com/developers/docs/resources/channel#overwrite-object
 #[cfg_attr(feature = "clone", derive(Clone))]
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
-pub struct Overwrite {}
+pub struct Overwrite {
+    pub id: OverwriteId,
+    pub r#type: OverwriteType,
+    pub allow: Permissions,
+    pub deny: Permissions,
+}
+
+/// ID of the role or user an overwrite applies to.
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct OverwriteId(pub String);
+
+impl From<RoleId> for OverwriteId {
+    fn from(value: RoleId) -> Self {
+        OverwriteId(value.0)
+    }
+}
+
+impl From<UserId> for OverwriteId {
+    fn from(value: UserId) -> Self {
+        OverwriteId(value.0)
+    }
+}
+
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize_repr, Serialize_repr))]
+#[cfg_attr(feature = "serde", repr(u8))]
+pub enum OverwriteType {
+    Role = 0,
+    Member = 1,
+}
 
 /// Discord docs: https://discord.com/developers/docs/resources/channel#thread-metadata-object
 #[cfg_attr(feature = "clone", derive(Clone))]
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
-pub struct ThreadMetadata {}
+pub struct ThreadMetadata {
+    pub archived: bool,
+    /// Minutes of inactivity before the thread is archived: 60, 1440, 4320, or 10080.
+    pub auto_archive_duration: u32,
+    /// When the archive status was last changed.
+    #[cfg_attr(feature = "serde", serde(with = "iso8601"))]
+    pub archive_timestamp: OffsetDateTime,
+    pub locked: bool,
+    /// Whether non-moderators can add other non-moderators to a private thread.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub invitable: Option<bool>,
+    /// Only set for threads created after 2022-01-09.
+    #[cfg_attr(feature = "serde", serde(default, with = "iso8601::option"))]
+    pub create_timestamp: Option<OffsetDateTime>,
+}
 
 /// Discord docs: https://discord.com/developers/docs/resources/channel#thread-member-object
 #[cfg_attr(feature = "clone", derive(Clone))]
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
-pub struct ThreadMember {}
+pub struct ThreadMember {
+    /// Left out in `GUILD_CREATE` events.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub id: Option<ChannelId>,
+    /// Left out in `GUILD_CREATE` events.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub user_id: Option<UserId>,
+    #[cfg_attr(feature = "serde", serde(with = "iso8601"))]
+    pub join_timestamp: OffsetDateTime,
+    /// Only used for notification settings.
+    pub flags: u64,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub member: Option<GuildMember>,
+}
 
 /// Discord docs: https://discord.com/developers/docs/resources/channel#default-reaction-object
 #[cfg_attr(feature = "clone", derive(Clone))]
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
-pub struct DefaultReaction {}
+pub struct DefaultReaction {
+    pub emoji_id: Option<EmojiId>,
+    pub emoji_name: Option<String>,
+}
 
 /// Discord docs: https://discord.com/developers/docs/resources/channel#forum-tag-object
 #[cfg_attr(feature = "clone", derive(Clone))]
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
-pub struct ForumTag {}
+pub struct ForumTag {
+    pub id: ForumTagId,
+    pub name: String,
+    /// Whether only members with `MANAGE_THREADS` can add or remove the tag.
+    pub moderated: bool,
+    pub emoji_id: Option<EmojiId>,
+    pub emoji_name: Option<String>,
+}
+
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct ForumTagId(pub String);
 
 /// Discord docs: https://discord.com/developers/docs/resources/channel#embed-object
+#[derive(Default)]
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct Embed {
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub title: Option<String>,
+    /// Always `rich` for embeds sent by bots.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub r#type: Option<String>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub description: Option<String>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub url: Option<String>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            with = "rfc3339::option",
+            skip_serializing_if = "Option::is_none"
+        )
+    )]
+    pub timestamp: Option<OffsetDateTime>,
+    /// RGB color as an integer (eg, `0x5865F2`).
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub color: Option<u32>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub footer: Option<EmbedFooter>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub image: Option<EmbedMedia>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub thumbnail: Option<EmbedMedia>,
+    /// Can't be set by bots.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub video: Option<EmbedMedia>,
+    /// Can't be set by bots.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub provider: Option<EmbedProvider>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub author: Option<EmbedAuthor>,
+    /// Up to 25 fields.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub fields: Vec<EmbedField>,
+}
+
+/// Discord docs: https://discord.com/developers/docs/resources/channel#embed-object-embed-footer-structure
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct EmbedFooter {
+    pub text: String,
+    /// Only HTTP(S) URLs and `attachment://filename` are supported.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub icon_url: Option<String>,
+    /// Set by Discord.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub proxy_icon_url: Option<String>,
+}
+
+/// Image, thumbnail, or video of an embed.
+///
+/// Discord docs: https://discord.com/developers/docs/resources/channel#embed-object-embed-image-structure
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct EmbedMedia {
+    /// Only HTTP(S) URLs and `attachment://filename` are supported.
+    pub url: String,
+    /// Set by Discord, as are the dimensions.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub proxy_url: Option<String>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub height: Option<u32>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub width: Option<u32>,
+}
+
+/// Discord docs: https://discord.com/developers/docs/resources/channel#embed-object-embed-provider-structure
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct EmbedProvider {
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub name: Option<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub url: Option<String>,
+}
+
+/// Discord docs: https://discord.com/developers/docs/resources/channel#embed-object-embed-author-structure
 #[cfg_attr(feature = "clone", derive(Clone))]
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
-pub struct Embed {}
+pub struct EmbedAuthor {
+    pub name: String,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub url: Option<String>,
+    /// Only HTTP(S) URLs and `attachment://filename` are supported.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub icon_url: Option<String>,
+    /// Set by Discord.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub proxy_icon_url: Option<String>,
+}
+
+/// Discord docs: https://discord.com/developers/docs/resources/channel#embed-object-embed-field-structure
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct EmbedField {
+    pub name: String,
+    pub value: String,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub inline: bool,
+}
 
 /// Discord docs: https://discord.com/developers/docs/resources/channel#attachment-object
 #[cfg_attr(feature = "clone", derive(Clone))]
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
-pub struct Attachment {}
+pub struct Attachment {
+    pub id: AttachmentId,
+    pub filename: String,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub title: Option<String>,
+    /// Alt text.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub description: Option<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub content_type: Option<String>,
+    /// Bytes.
+    pub size: u64,
+    pub url: String,
+    pub proxy_url: String,
+    /// Only set for images and videos.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub height: Option<u32>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub width: Option<u32>,
+    /// Ephemeral attachments are removed after a set period of time.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub ephemeral: bool,
+    /// Length of a voice message in seconds.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub duration_secs: Option<f64>,
+    /// Base64 encoded waveform of a voice message.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub waveform: Option<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub flags: Option<AttachmentFlags>,
+}
+
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct AttachmentId(pub String);
+
+flags!(attachment_flags: u32 {
+    IsRemix = 1 << 2,
+});
+pub use attachment_flags::Flags as AttachmentFlags;
 
+/// Mentions in the content that should notify users, with `Default` notifying nobody.
+/// Messages sent without allowed mentions notify everyone mentioned.
+///
 /// Discord docs: https://discord.com/developers/docs/resources/channel#allowed-mentions-object
+#[derive(Default)]
 #[cfg_attr(feature = "clone", derive(Clone))]
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
-pub struct AllowedMentions {}
+pub struct AllowedMentions {
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub parse: Vec<AllowedMentionType>,
+    /// Up to 100 roles. Can't be used with `AllowedMentionType::Roles`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub roles: Vec<RoleId>,
+    /// Up to 100 users. Can't be used with `AllowedMentionType::Users`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub users: Vec<UserId>,
+    /// Whether to notify the author of the message being replied to.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub replied_user: bool,
+}
+
+/// Discord docs: https://discord.com/developers/docs/resources/channel#allowed-mentions-object-allowed-mention-types
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum AllowedMentionType {
+    Roles,
+    Users,
+    Everyone,
+}
 
 /// Discord docs: https://discord.com/developers/docs/resources/channel#role-subscription-data-object
 #[cfg_attr(feature = "clone", derive(Clone))]
@@ -0,0 +1,58 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "serde")]
+use serde_repr::{Deserialize_repr, Serialize_repr};
+#[cfg(feature = "serde")]
+use time::serde::iso8601;
+use time::OffsetDateTime;
+
+use super::{
+    application::{ApplicationId, SkuId},
+    guild::GuildId,
+    user::UserId,
+};
+
+/// Discord docs: https://discord.com/developers/docs/resources/entitlement#entitlement-object
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct Entitlement {
+    pub id: EntitlementId,
+    pub sku_id: SkuId,
+    pub application_id: ApplicationId,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub user_id: Option<UserId>,
+    pub r#type: EntitlementType,
+    pub deleted: bool,
+    #[cfg_attr(feature = "serde", serde(default))]
+    #[cfg_attr(feature = "serde", serde(with = "iso8601::option"))]
+    pub starts_at: Option<OffsetDateTime>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    #[cfg_attr(feature = "serde", serde(with = "iso8601::option"))]
+    pub ends_at: Option<OffsetDateTime>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub guild_id: Option<GuildId>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub consumed: Option<bool>,
+}
+
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct EntitlementId(pub String);
+
+/// Discord docs: https://discord.com/developers/docs/resources/entitlement#entitlement-object-entitlement-types
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize_repr, Serialize_repr))]
+#[cfg_attr(feature = "serde", repr(u8))]
+pub enum EntitlementType {
+    Purchase = 1,
+    PremiumSubscription = 2,
+    DeveloperGift = 3,
+    TestModePurchase = 4,
+    FreePurchase = 5,
+    UserGift = 6,
+    PremiumPurchase = 7,
+    ApplicationSubscription = 8,
+}
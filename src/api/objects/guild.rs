@@ -1,5 +1,22 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "serde")]
+use serde_repr::{Deserialize_repr, Serialize_repr};
+#[cfg(feature = "serde")]
+use time::serde::iso8601;
+use time::OffsetDateTime;
+
+use crate::flags;
+
+use super::{
+    application::ApplicationId,
+    channel::ChannelId,
+    emoji::Emoji,
+    permissions::{Permissions, Role, RoleId},
+    sticker::Sticker,
+    user::{AvatarDecorationData, User, UserId},
+    ImageHash,
+};
 
 #[cfg_attr(feature = "clone", derive(Clone))]
 #[cfg_attr(feature = "debug", derive(Debug))]
@@ -10,7 +27,201 @@ pub struct GuildId(pub String);
 #[cfg_attr(feature = "clone", derive(Clone))]
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
-pub struct Guild {}
+pub struct Guild {
+    pub id: GuildId,
+    /// 2-100 characters.
+    pub name: String,
+    pub icon: Option<ImageHash>,
+    /// Only set in template objects.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub icon_hash: Option<ImageHash>,
+    pub splash: Option<ImageHash>,
+    pub discovery_splash: Option<ImageHash>,
+    /// Whether the current user owns the guild. Only set for `GET /users/@me/guilds`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub owner: Option<bool>,
+    pub owner_id: UserId,
+    /// Permissions of the current user, including overwrites. Only set for `GET /users/@me/guilds`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub permissions: Option<Permissions>,
+    pub afk_channel_id: Option<ChannelId>,
+    /// Seconds.
+    pub afk_timeout: u32,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub widget_enabled: Option<bool>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub widget_channel_id: Option<ChannelId>,
+    pub verification_level: VerificationLevel,
+    pub default_message_notifications: DefaultMessageNotificationLevel,
+    pub explicit_content_filter: ExplicitContentFilterLevel,
+    pub roles: Vec<Role>,
+    pub emojis: Vec<Emoji>,
+    pub features: Vec<GuildFeature>,
+    pub mfa_level: MfaLevel,
+    /// Application that created the guild, if created by a bot.
+    pub application_id: Option<ApplicationId>,
+    /// Where welcome messages and boost events are posted.
+    pub system_channel_id: Option<ChannelId>,
+    pub system_channel_flags: SystemChannelFlags,
+    pub rules_channel_id: Option<ChannelId>,
+    /// Always `None` except for the largest guilds.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub max_presences: Option<u32>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub max_members: Option<u32>,
+    pub vanity_url_code: Option<String>,
+    pub description: Option<String>,
+    pub banner: Option<ImageHash>,
+    pub premium_tier: PremiumTier,
+    /// Number of boosts.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub premium_subscription_count: Option<u32>,
+    /// Defaults to `en-US`.
+    pub preferred_locale: String,
+    /// Where admins and moderators of community guilds receive notices from Discord.
+    pub public_updates_channel_id: Option<ChannelId>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub max_video_channel_users: Option<u32>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub max_stage_video_channel_users: Option<u32>,
+    /// Only set by Get Guild with `with_counts`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub approximate_member_count: Option<u32>,
+    /// Only set by Get Guild with `with_counts`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub approximate_presence_count: Option<u32>,
+    /// Only set for guilds with the `INVITE_SPLASH` feature, in invites.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub welcome_screen: Option<WelcomeScreen>,
+    pub nsfw_level: GuildNsfwLevel,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub stickers: Vec<Sticker>,
+    pub premium_progress_bar_enabled: bool,
+    pub safety_alerts_channel_id: Option<ChannelId>,
+}
+
+/// Discord docs: https://discord.com/developers/docs/resources/guild#guild-object-verification-level
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize_repr, Serialize_repr))]
+#[cfg_attr(feature = "serde", repr(u8))]
+pub enum VerificationLevel {
+    None = 0,
+    /// Verified email.
+    Low = 1,
+    /// Registered for more than 5 minutes.
+    Medium = 2,
+    /// Member of the guild for more than 10 minutes.
+    High = 3,
+    /// Verified phone number.
+    VeryHigh = 4,
+}
+
+/// Discord docs: https://discord.com/developers/docs/resources/guild#guild-object-default-message-notification-level
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize_repr, Serialize_repr))]
+#[cfg_attr(feature = "serde", repr(u8))]
+pub enum DefaultMessageNotificationLevel {
+    AllMessages = 0,
+    OnlyMentions = 1,
+}
+
+/// Discord docs: https://discord.com/developers/docs/resources/guild#guild-object-explicit-content-filter-level
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize_repr, Serialize_repr))]
+#[cfg_attr(feature = "serde", repr(u8))]
+pub enum ExplicitContentFilterLevel {
+    Disabled = 0,
+    MembersWithoutRoles = 1,
+    AllMembers = 2,
+}
+
+/// Discord docs: https://discord.com/developers/docs/resources/guild#guild-object-mfa-level
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize_repr, Serialize_repr))]
+#[cfg_attr(feature = "serde", repr(u8))]
+pub enum MfaLevel {
+    None = 0,
+    /// Moderators need 2FA enabled to take moderation actions.
+    Elevated = 1,
+}
+
+/// Discord docs: https://discord.com/developers/docs/resources/guild#guild-object-guild-nsfw-level
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize_repr, Serialize_repr))]
+#[cfg_attr(feature = "serde", repr(u8))]
+pub enum GuildNsfwLevel {
+    Default = 0,
+    Explicit = 1,
+    Safe = 2,
+    AgeRestricted = 3,
+}
+
+/// Boost level.
+///
+/// Discord docs: https://discord.com/developers/docs/resources/guild#guild-object-premium-tier
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize_repr, Serialize_repr))]
+#[cfg_attr(feature = "serde", repr(u8))]
+pub enum PremiumTier {
+    None = 0,
+    Tier1 = 1,
+    Tier2 = 2,
+    Tier3 = 3,
+}
+
+flags!(system_channel_flags: u8 {
+    SuppressJoinNotifications = 1 << 0,
+    SuppressPremiumSubscriptions = 1 << 1,
+    SuppressGuildReminderNotifications = 1 << 2,
+    SuppressJoinNotificationReplies = 1 << 3,
+    SuppressRoleSubscriptionPurchaseNotifications = 1 << 4,
+    SuppressRoleSubscriptionPurchaseNotificationReplies = 1 << 5,
+});
+pub use system_channel_flags::Flags as SystemChannelFlags;
+
+/// Discord docs: https://discord.com/developers/docs/resources/guild#guild-object-guild-features
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "SCREAMING_SNAKE_CASE"))]
+pub enum GuildFeature {
+    AnimatedBanner,
+    AnimatedIcon,
+    ApplicationCommandPermissionsV2,
+    AutoModeration,
+    Banner,
+    Community,
+    CreatorMonetizableProvisional,
+    CreatorStorePage,
+    DeveloperSupportServer,
+    Discoverable,
+    Featurable,
+    InvitesDisabled,
+    InviteSplash,
+    MemberVerificationGateEnabled,
+    MoreStickers,
+    News,
+    Partnered,
+    PreviewEnabled,
+    RaidAlertsDisabled,
+    RoleIcons,
+    RoleSubscriptionsAvailableForPurchase,
+    RoleSubscriptionsEnabled,
+    TicketedEventsEnabled,
+    VanityUrl,
+    Verified,
+    VipRegions,
+    WelcomeScreenEnabled,
+    /// Discord adds features regularly, so unknown features are kept as is.
+    #[cfg_attr(feature = "serde", serde(untagged))]
+    Unknown(String),
+}
 
 /// Discord docs: https://discord.com/developers/docs/resources/guild#unavailable-guild-object
 #[cfg_attr(feature = "clone", derive(Clone))]
@@ -22,7 +233,40 @@ pub struct UnavailableGuild {}
 #[cfg_attr(feature = "clone", derive(Clone))]
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
-pub struct GuildPreview {}
+pub struct GuildPreview {
+    pub id: GuildId,
+    pub name: String,
+    pub icon: Option<ImageHash>,
+    pub splash: Option<ImageHash>,
+    pub discovery_splash: Option<ImageHash>,
+    pub emojis: Vec<Emoji>,
+    pub features: Vec<GuildFeature>,
+    pub approximate_member_count: u32,
+    pub approximate_presence_count: u32,
+    pub description: Option<String>,
+    pub stickers: Vec<Sticker>,
+}
+
+/// Discord docs: https://discord.com/developers/docs/resources/guild#get-guild-prune-count
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct GuildPruneCount {
+    /// `None` when beginning a prune without `compute_prune_count`.
+    pub pruned: Option<u32>,
+}
+
+/// Partial invite for the guild's vanity URL.
+///
+/// Discord docs: https://discord.com/developers/docs/resources/guild#get-guild-vanity-url
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct GuildVanityUrl {
+    /// `None` when the guild doesn't have a vanity URL set.
+    pub code: Option<String>,
+    pub uses: u32,
+}
 
 /// Discord docs: https://discord.com/developers/docs/resources/guild#guild-widget-settings-object
 #[cfg_attr(feature = "clone", derive(Clone))]
@@ -40,13 +284,111 @@ pub struct GuildWidget {}
 #[cfg_attr(feature = "clone", derive(Clone))]
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
-pub struct GuildMember {}
+pub struct GuildMember {
+    /// Left out of the member in `MESSAGE_CREATE` and `MESSAGE_UPDATE` events.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub user: Option<User>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub nick: Option<String>,
+    /// Guild-specific avatar.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub avatar: Option<ImageHash>,
+    /// Guild-specific banner.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub banner: Option<ImageHash>,
+    pub roles: Vec<RoleId>,
+    /// `None` for guest members of voice channels.
+    #[cfg_attr(feature = "serde", serde(default, with = "iso8601::option"))]
+    pub joined_at: Option<OffsetDateTime>,
+    /// When the member started boosting the guild.
+    #[cfg_attr(feature = "serde", serde(default, with = "iso8601::option"))]
+    pub premium_since: Option<OffsetDateTime>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub deaf: bool,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub mute: bool,
+    pub flags: GuildMemberFlags,
+    /// Whether the member hasn't passed membership screening yet.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub pending: Option<bool>,
+    /// Permissions of the member in the channel, including overwrites. Only set for interactions.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub permissions: Option<Permissions>,
+    /// When the member's timeout will expire, which may be in the past.
+    #[cfg_attr(feature = "serde", serde(default, with = "iso8601::option"))]
+    pub communication_disabled_until: Option<OffsetDateTime>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub avatar_decoration_data: Option<AvatarDecorationData>,
+}
+
+flags!(guild_member_flags: u16 {
+    DidRejoin = 1 << 0,
+    CompletedOnboarding = 1 << 1,
+    BypassesVerification = 1 << 2,
+    StartedOnboarding = 1 << 3,
+    IsGuest = 1 << 4,
+    StartedHomeActions = 1 << 5,
+    CompletedHomeActions = 1 << 6,
+    AutomodQuarantinedUsername = 1 << 7,
+    DmSettingsUpsellAcknowledged = 1 << 9,
+});
+pub use guild_member_flags::Flags as GuildMemberFlags;
 
 /// Discord docs: https://discord.com/developers/docs/resources/guild#integration-object
 #[cfg_attr(feature = "clone", derive(Clone))]
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
-pub struct Integration {}
+pub struct Integration {
+    pub id: IntegrationId,
+    pub name: String,
+    pub r#type: GuildIntegrationType,
+    pub enabled: bool,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub syncing: Option<bool>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub role_id: Option<RoleId>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub enable_emoticons: Option<bool>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub expire_behavior: Option<IntegrationExpireBehavior>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub expire_grace_period: Option<u64>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub user: Option<User>,
+    pub account: IntegrationAccount,
+    #[cfg_attr(feature = "serde", serde(default))]
+    #[cfg_attr(feature = "serde", serde(with = "iso8601::option"))]
+    pub synced_at: Option<OffsetDateTime>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub subscriber_count: Option<u64>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub revoked: Option<bool>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub application: Option<IntegrationApplication>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub scopes: Vec<String>,
+}
+
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum GuildIntegrationType {
+    Twitch,
+    Youtube,
+    Discord,
+    GuildSubscription,
+}
+
+/// Discord docs: https://discord.com/developers/docs/resources/guild#integration-object-integration-expire-behaviors
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize_repr, Serialize_repr))]
+#[cfg_attr(feature = "serde", repr(u8))]
+pub enum IntegrationExpireBehavior {
+    RemoveRole = 0,
+    Kick = 1,
+}
 
 #[cfg_attr(feature = "clone", derive(Clone))]
 #[cfg_attr(feature = "debug", derive(Debug))]
@@ -64,19 +406,42 @@ pub struct IntegrationId(pub String);
 #[cfg_attr(feature = "clone", derive(Clone))]
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
-pub struct IntegrationAccount {}
+pub struct IntegrationAccount {
+    pub id: String,
+    pub name: String,
+}
 
 /// Discord docs: https://discord.com/developers/docs/resources/guild#integration-application-object
 #[cfg_attr(feature = "clone", derive(Clone))]
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
-pub struct IntegrationApplication {}
+pub struct IntegrationApplication {
+    pub id: ApplicationId,
+    pub name: String,
+    pub icon: Option<ImageHash>,
+    pub description: String,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub bot: Option<User>,
+}
 
 /// Discord docs: https://discord.com/developers/docs/resources/guild#ban-object
 #[cfg_attr(feature = "clone", derive(Clone))]
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
-pub struct Ban {}
+pub struct Ban {
+    pub reason: Option<String>,
+    pub user: User,
+}
+
+/// Discord docs: https://discord.com/developers/docs/resources/guild#bulk-guild-ban-bulk-ban-response
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct BulkBan {
+    pub banned_users: Vec<UserId>,
+    /// Users that couldn't be banned, such as those already banned or with a higher role.
+    pub failed_users: Vec<UserId>,
+}
 
 /// Discord docs: https://discord.com/developers/docs/resources/guild#welcome-screen-object
 #[cfg_attr(feature = "clone", derive(Clone))]
@@ -95,3 +460,51 @@ pub struct GuildOnboarding {}
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub struct MembershipScreening {}
+
+#[cfg(feature = "serde")]
+#[test]
+fn bans() {
+    let bans: Vec<Ban> = serde_json::from_str(
+        r#"[
+            {
+                "reason": "mentioning b1nzy",
+                "user": {
+                    "username": "Mason",
+                    "discriminator": "9999",
+                    "global_name": null,
+                    "id": "53908099506183680",
+                    "avatar": "a_bab14f271d565501444b2ca3be944b25",
+                    "public_flags": 131141
+                }
+            },
+            {
+                "reason": null,
+                "user": {
+                    "username": "spammer",
+                    "discriminator": "0",
+                    "global_name": null,
+                    "id": "1234",
+                    "avatar": null
+                }
+            }
+        ]"#,
+    )
+    .unwrap();
+    assert_eq!(Some("mentioning b1nzy"), bans[0].reason.as_deref());
+    assert_eq!("53908099506183680", bans[0].user.id.0);
+    assert!(bans[1].reason.is_none());
+
+    let bulk_ban: BulkBan = serde_json::from_str(
+        r#"{"banned_users": ["1234", "5678"], "failed_users": ["53908099506183680"]}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        vec!["1234", "5678"],
+        bulk_ban
+            .banned_users
+            .iter()
+            .map(|user_id| user_id.0.as_str())
+            .collect::<Vec<_>>()
+    );
+    assert_eq!("53908099506183680", bulk_ban.failed_users[0].0);
+}
@@ -1,5 +1,9 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "serde")]
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+use super::channel::{Message, MessageId};
 
 /// Discord docs: https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object
 #[cfg_attr(feature = "clone", derive(Clone))]
@@ -7,11 +11,16 @@ use serde::{Deserialize, Serialize};
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub struct Interaction {}
 
-/// Discord docs: https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object-interaction-type
 #[cfg_attr(feature = "clone", derive(Clone))]
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "SCREAMING_SNAKE_CASE"))]
+pub struct InteractionId(pub String);
+
+/// Discord docs: https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object-interaction-type
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize_repr, Serialize_repr))]
+#[cfg_attr(feature = "serde", repr(u8))]
 pub enum InteractionType {
     Ping = 1,
     ApplicationCommand = 2,
@@ -23,12 +32,79 @@ pub enum InteractionType {
 /// Discord docs: https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object-interaction-context-types
 #[cfg_attr(feature = "clone", derive(Clone))]
 #[cfg_attr(feature = "debug", derive(Debug))]
-#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "SCREAMING_SNAKE_CASE"))]
+#[cfg_attr(feature = "serde", derive(Deserialize_repr, Serialize_repr))]
+#[cfg_attr(feature = "serde", repr(u8))]
 pub enum InteractionContextType {
     Guild = 0,
     BotDm = 1,
     PrivateChannel = 2,
 }
 
+/// Discord docs: https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-response-object-interaction-callback-type
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize_repr, Serialize_repr))]
+#[cfg_attr(feature = "serde", repr(u8))]
+pub enum InteractionCallbackType {
+    Pong = 1,
+    ChannelMessageWithSource = 4,
+    DeferredChannelMessageWithSource = 5,
+    DeferredUpdateMessage = 6,
+    UpdateMessage = 7,
+    ApplicationCommandAutocompleteResult = 8,
+    Modal = 9,
+    LaunchActivity = 12,
+}
+
+/// Returned when responding to an interaction with `with_response` set.
+///
+/// Discord docs: https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-callback-interaction-callback-response-object
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct InteractionCallbackResponse {
+    pub interaction: InteractionCallback,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub resource: Option<InteractionCallbackResource>,
+}
+
+/// Discord docs: https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-callback-interaction-callback-object
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct InteractionCallback {
+    pub id: InteractionId,
+    pub r#type: InteractionType,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub activity_instance_id: Option<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub response_message_id: Option<MessageId>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub response_message_loading: Option<bool>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub response_message_ephemeral: Option<bool>,
+}
+
+/// Discord docs: https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-callback-interaction-callback-resource-object
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct InteractionCallbackResource {
+    pub r#type: InteractionCallbackType,
+    /// Only for `LaunchActivity` responses.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub activity_instance: Option<ActivityInstance>,
+    /// Only for responses that create or update a message.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub message: Option<Message>,
+}
+
+/// Discord docs: https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-callback-interaction-callback-activity-instance-resource
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct ActivityInstance {
+    pub id: String,
+}
+
 // TODO: There are more objects to add from https://discord.com/developers/docs/interactions/receiving-and-responding
@@ -1,5 +1,7 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "serde")]
+use serde_repr::{Deserialize_repr, Serialize_repr};
 
 /// Discord docs: https://discord.com/developers/docs/resources/invite#invite-object
 #[cfg_attr(feature = "clone", derive(Clone))]
@@ -20,7 +22,8 @@ pub enum InviteType {
 /// Discord docs: https://discord.com/developers/docs/resources/invite#invite-object-invite-target-types
 #[cfg_attr(feature = "clone", derive(Clone))]
 #[cfg_attr(feature = "debug", derive(Debug))]
-#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "serde", derive(Deserialize_repr, Serialize_repr))]
+#[cfg_attr(feature = "serde", repr(u8))]
 pub enum InviteTargetType {
     Stream = 1,
     EmbeddedApplication = 2,
@@ -0,0 +1,356 @@
+#[cfg(feature = "serde")]
+use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+#[cfg(feature = "serde")]
+use serde_json::Value;
+#[cfg(feature = "serde")]
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+use super::{application::SkuId, channel::ChannelType, emoji::Emoji};
+
+/// Discord docs: https://discord.com/developers/docs/interactions/message-components#component-object
+// Boxing the select menus would make building components more awkward for little gain.
+#[allow(clippy::large_enum_variant)]
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub enum Component {
+    ActionRow(ActionRow),
+    Button(Button),
+    StringSelect(SelectMenu),
+    /// Only allowed in modals.
+    TextInput(TextInput),
+    UserSelect(SelectMenu),
+    RoleSelect(SelectMenu),
+    MentionableSelect(SelectMenu),
+    ChannelSelect(SelectMenu),
+}
+
+impl Component {
+    pub const FIELD_TYPE: &'static str = "type";
+
+    pub fn component_type(&self) -> ComponentType {
+        match self {
+            Component::ActionRow(_) => ComponentType::ActionRow,
+            Component::Button(_) => ComponentType::Button,
+            Component::StringSelect(_) => ComponentType::StringSelect,
+            Component::TextInput(_) => ComponentType::TextInput,
+            Component::UserSelect(_) => ComponentType::UserSelect,
+            Component::RoleSelect(_) => ComponentType::RoleSelect,
+            Component::MentionableSelect(_) => ComponentType::MentionableSelect,
+            Component::ChannelSelect(_) => ComponentType::ChannelSelect,
+        }
+    }
+}
+
+// Same problem as `EventPayload`: serde can't tag enums with integers,
+// so the `type` is added to (or read from) the component's own fields.
+#[cfg(feature = "serde")]
+impl Serialize for Component {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let fields = match self {
+            Component::ActionRow(component) => serde_json::to_value(component),
+            Component::Button(component) => serde_json::to_value(component),
+            Component::TextInput(component) => serde_json::to_value(component),
+            Component::StringSelect(component)
+            | Component::UserSelect(component)
+            | Component::RoleSelect(component)
+            | Component::MentionableSelect(component)
+            | Component::ChannelSelect(component) => serde_json::to_value(component),
+        };
+        let mut fields = fields.map_err(ser::Error::custom)?;
+        let Value::Object(map) = &mut fields else {
+            return Err(ser::Error::custom("Components should serialize to objects"));
+        };
+        map.insert(
+            Self::FIELD_TYPE.to_string(),
+            serde_json::to_value(self.component_type()).map_err(ser::Error::custom)?,
+        );
+
+        fields.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Component {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let fields = Value::deserialize(deserializer)?;
+        let component_type = fields
+            .get(Self::FIELD_TYPE)
+            .ok_or(de::Error::missing_field(Self::FIELD_TYPE))?;
+        let component_type =
+            ComponentType::deserialize(component_type).map_err(de::Error::custom)?;
+
+        Ok(match component_type {
+            ComponentType::ActionRow => {
+                Component::ActionRow(serde_json::from_value(fields).map_err(de::Error::custom)?)
+            }
+            ComponentType::Button => {
+                Component::Button(serde_json::from_value(fields).map_err(de::Error::custom)?)
+            }
+            ComponentType::TextInput => {
+                Component::TextInput(serde_json::from_value(fields).map_err(de::Error::custom)?)
+            }
+            select_type => {
+                let select_menu = serde_json::from_value(fields).map_err(de::Error::custom)?;
+                match select_type {
+                    ComponentType::UserSelect => Component::UserSelect(select_menu),
+                    ComponentType::RoleSelect => Component::RoleSelect(select_menu),
+                    ComponentType::MentionableSelect => Component::MentionableSelect(select_menu),
+                    ComponentType::ChannelSelect => Component::ChannelSelect(select_menu),
+                    _ => Component::StringSelect(select_menu),
+                }
+            }
+        })
+    }
+}
+
+/// Discord docs: https://discord.com/developers/docs/interactions/message-components#component-object-component-types
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize_repr, Serialize_repr))]
+#[cfg_attr(feature = "serde", repr(u8))]
+pub enum ComponentType {
+    ActionRow = 1,
+    Button = 2,
+    StringSelect = 3,
+    TextInput = 4,
+    UserSelect = 5,
+    RoleSelect = 6,
+    MentionableSelect = 7,
+    ChannelSelect = 8,
+}
+
+/// Holds up to 5 buttons, or a single select menu or text input.
+///
+/// Discord docs: https://discord.com/developers/docs/interactions/message-components#action-rows
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct ActionRow {
+    pub components: Vec<Component>,
+}
+
+/// Discord docs: https://discord.com/developers/docs/interactions/message-components#button-object
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct Button {
+    pub style: ButtonStyle,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub label: Option<String>,
+    /// Only `id`, `name`, and `animated` are used.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub emoji: Option<Emoji>,
+    /// Required for every style except `Link` and `Premium`.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub custom_id: Option<String>,
+    /// Required for `Premium` buttons.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub sku_id: Option<SkuId>,
+    /// Required for `Link` buttons.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub url: Option<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub disabled: bool,
+}
+
+/// Discord docs: https://discord.com/developers/docs/interactions/message-components#button-object-button-styles
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize_repr, Serialize_repr))]
+#[cfg_attr(feature = "serde", repr(u8))]
+pub enum ButtonStyle {
+    Primary = 1,
+    Secondary = 2,
+    Success = 3,
+    Danger = 4,
+    Link = 5,
+    Premium = 6,
+}
+
+/// Used by every type of select menu, with `options` only used by string selects
+/// and `channel_types` only used by channel selects.
+///
+/// Discord docs: https://discord.com/developers/docs/interactions/message-components#select-menu-object
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct SelectMenu {
+    pub custom_id: String,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub options: Vec<SelectOption>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub channel_types: Vec<ChannelType>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub placeholder: Option<String>,
+    /// Only used by user, role, mentionable, and channel selects.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub default_values: Vec<SelectDefaultValue>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub min_values: Option<u8>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub max_values: Option<u8>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub disabled: bool,
+}
+
+/// Discord docs: https://discord.com/developers/docs/interactions/message-components#select-menu-object-select-option-structure
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct SelectOption {
+    pub label: String,
+    pub value: String,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub description: Option<String>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub emoji: Option<Emoji>,
+    /// Whether the option is selected by default.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub default: bool,
+}
+
+/// Discord docs: https://discord.com/developers/docs/interactions/message-components#select-menu-object-select-default-value-structure
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct SelectDefaultValue {
+    /// ID of the user, role, or channel.
+    pub id: String,
+    pub r#type: SelectDefaultValueType,
+}
+
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum SelectDefaultValueType {
+    User,
+    Role,
+    Channel,
+}
+
+/// Discord docs: https://discord.com/developers/docs/interactions/message-components#text-input-object
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct TextInput {
+    pub custom_id: String,
+    /// Only sent when creating the text input.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub style: Option<TextInputStyle>,
+    /// Only sent when creating the text input.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub label: Option<String>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub min_length: Option<u16>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub max_length: Option<u16>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub required: Option<bool>,
+    /// Pre-filled value when creating the text input, or the submitted value in a modal submit.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub value: Option<String>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub placeholder: Option<String>,
+}
+
+/// Discord docs: https://discord.com/developers/docs/interactions/message-components#text-input-object-text-input-styles
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize_repr, Serialize_repr))]
+#[cfg_attr(feature = "serde", repr(u8))]
+pub enum TextInputStyle {
+    Short = 1,
+    Paragraph = 2,
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_component() {
+    use serde_json::json;
+
+    let row = json!({
+        "type": 1,
+        "components": [
+            {"type": 2, "style": 1, "label": "Accept", "custom_id": "accept"},
+            {"type": 5, "custom_id": "users", "max_values": 3},
+        ],
+    });
+    let component: Component = serde_json::from_value(row.clone()).unwrap();
+    let Component::ActionRow(action_row) = &component else {
+        panic!("Expected an action row");
+    };
+    assert!(matches!(action_row.components[0], Component::Button(_)));
+    assert!(matches!(action_row.components[1], Component::UserSelect(_)));
+
+    assert_eq!(
+        json!({
+            "type": 1,
+            "components": [
+                {"type": 2, "style": 1, "label": "Accept", "custom_id": "accept", "disabled": false},
+                {"type": 5, "custom_id": "users", "max_values": 3, "disabled": false},
+            ],
+        }),
+        serde_json::to_value(&component).unwrap()
+    );
+}
@@ -8,11 +8,13 @@ pub mod audit_log;
 pub mod auto_moderation;
 pub mod channel;
 pub mod emoji;
+pub mod entitlement;
 pub mod guild;
 pub mod guild_scheduled_event;
 pub mod guild_template;
 pub mod interactions;
 pub mod invite;
+pub mod message_components;
 pub mod permissions;
 pub mod poll;
 pub mod stage_instance;
@@ -27,72 +29,211 @@ pub mod webhook;
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub struct ImageHash(pub String);
 
+/// Text for each locale, where locales without a translation are `None`.
+///
+/// Discord docs: https://discord.com/developers/docs/reference#locales
 #[cfg_attr(feature = "clone", derive(Clone))]
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub struct Translation<T> {
-    #[cfg_attr(feature = "serde", serde(rename = "id"))]
-    pub indonesian: T,
-    #[cfg_attr(feature = "serde", serde(rename = "da"))]
-    pub danish: T,
-    #[cfg_attr(feature = "serde", serde(rename = "de"))]
-    pub german: T,
-    #[cfg_attr(feature = "serde", serde(rename = "en-GB"))]
-    pub english_uk: T,
-    #[cfg_attr(feature = "serde", serde(rename = "en-US"))]
-    pub english_us: T,
-    #[cfg_attr(feature = "serde", serde(rename = "es-ES"))]
-    pub spanish: T,
-    #[cfg_attr(feature = "serde", serde(rename = "es-419"))]
-    pub spanish_latam: T,
-    #[cfg_attr(feature = "serde", serde(rename = "fr"))]
-    pub french: T,
-    #[cfg_attr(feature = "serde", serde(rename = "hr"))]
-    pub croatian: T,
-    #[cfg_attr(feature = "serde", serde(rename = "it"))]
-    pub italian: T,
-    #[cfg_attr(feature = "serde", serde(rename = "lt"))]
-    pub lithuanian: T,
-    #[cfg_attr(feature = "serde", serde(rename = "hu"))]
-    pub hungarian: T,
-    #[cfg_attr(feature = "serde", serde(rename = "nl"))]
-    pub dutch: T,
-    #[cfg_attr(feature = "serde", serde(rename = "no"))]
-    pub norwegian: T,
-    #[cfg_attr(feature = "serde", serde(rename = "pl"))]
-    pub polish: T,
-    #[cfg_attr(feature = "serde", serde(rename = "pt-BR"))]
-    pub portuguese_brazilian: T,
-    #[cfg_attr(feature = "serde", serde(rename = "ro"))]
-    pub romanian_romania: T,
-    #[cfg_attr(feature = "serde", serde(rename = "fi"))]
-    pub finnish: T,
-    #[cfg_attr(feature = "serde", serde(rename = "sv-SE"))]
-    pub swedish: T,
-    #[cfg_attr(feature = "serde", serde(rename = "vi"))]
-    pub vietnamese: T,
-    #[cfg_attr(feature = "serde", serde(rename = "tr"))]
-    pub turkish: T,
-    #[cfg_attr(feature = "serde", serde(rename = "cs"))]
-    pub czech: T,
-    #[cfg_attr(feature = "serde", serde(rename = "el"))]
-    pub greek: T,
-    #[cfg_attr(feature = "serde", serde(rename = "bg"))]
-    pub bulgarian: T,
-    #[cfg_attr(feature = "serde", serde(rename = "ru"))]
-    pub russian: T,
-    #[cfg_attr(feature = "serde", serde(rename = "uk"))]
-    pub ukrainian: T,
-    #[cfg_attr(feature = "serde", serde(rename = "hi"))]
-    pub hindi: T,
-    #[cfg_attr(feature = "serde", serde(rename = "th"))]
-    pub thai: T,
-    #[cfg_attr(feature = "serde", serde(rename = "zh-CN"))]
-    pub chinese_china: T,
-    #[cfg_attr(feature = "serde", serde(rename = "ja"))]
-    pub japanese: T,
-    #[cfg_attr(feature = "serde", serde(rename = "zh-TW"))]
-    pub chinese_taiwan: T,
-    #[cfg_attr(feature = "serde", serde(rename = "ko"))]
-    pub korean: T,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "id", skip_serializing_if = "Option::is_none")
+    )]
+    pub indonesian: Option<T>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "da", skip_serializing_if = "Option::is_none")
+    )]
+    pub danish: Option<T>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "de", skip_serializing_if = "Option::is_none")
+    )]
+    pub german: Option<T>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "en-GB", skip_serializing_if = "Option::is_none")
+    )]
+    pub english_uk: Option<T>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "en-US", skip_serializing_if = "Option::is_none")
+    )]
+    pub english_us: Option<T>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "es-ES", skip_serializing_if = "Option::is_none")
+    )]
+    pub spanish: Option<T>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "es-419", skip_serializing_if = "Option::is_none")
+    )]
+    pub spanish_latam: Option<T>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "fr", skip_serializing_if = "Option::is_none")
+    )]
+    pub french: Option<T>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "hr", skip_serializing_if = "Option::is_none")
+    )]
+    pub croatian: Option<T>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "it", skip_serializing_if = "Option::is_none")
+    )]
+    pub italian: Option<T>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "lt", skip_serializing_if = "Option::is_none")
+    )]
+    pub lithuanian: Option<T>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "hu", skip_serializing_if = "Option::is_none")
+    )]
+    pub hungarian: Option<T>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "nl", skip_serializing_if = "Option::is_none")
+    )]
+    pub dutch: Option<T>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "no", skip_serializing_if = "Option::is_none")
+    )]
+    pub norwegian: Option<T>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "pl", skip_serializing_if = "Option::is_none")
+    )]
+    pub polish: Option<T>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "pt-BR", skip_serializing_if = "Option::is_none")
+    )]
+    pub portuguese_brazilian: Option<T>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "ro", skip_serializing_if = "Option::is_none")
+    )]
+    pub romanian_romania: Option<T>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "fi", skip_serializing_if = "Option::is_none")
+    )]
+    pub finnish: Option<T>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "sv-SE", skip_serializing_if = "Option::is_none")
+    )]
+    pub swedish: Option<T>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "vi", skip_serializing_if = "Option::is_none")
+    )]
+    pub vietnamese: Option<T>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "tr", skip_serializing_if = "Option::is_none")
+    )]
+    pub turkish: Option<T>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "cs", skip_serializing_if = "Option::is_none")
+    )]
+    pub czech: Option<T>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "el", skip_serializing_if = "Option::is_none")
+    )]
+    pub greek: Option<T>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "bg", skip_serializing_if = "Option::is_none")
+    )]
+    pub bulgarian: Option<T>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "ru", skip_serializing_if = "Option::is_none")
+    )]
+    pub russian: Option<T>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "uk", skip_serializing_if = "Option::is_none")
+    )]
+    pub ukrainian: Option<T>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "hi", skip_serializing_if = "Option::is_none")
+    )]
+    pub hindi: Option<T>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "th", skip_serializing_if = "Option::is_none")
+    )]
+    pub thai: Option<T>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "zh-CN", skip_serializing_if = "Option::is_none")
+    )]
+    pub chinese_china: Option<T>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "ja", skip_serializing_if = "Option::is_none")
+    )]
+    pub japanese: Option<T>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "zh-TW", skip_serializing_if = "Option::is_none")
+    )]
+    pub chinese_taiwan: Option<T>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "ko", skip_serializing_if = "Option::is_none")
+    )]
+    pub korean: Option<T>,
+}
+
+// Derived `Default` would need `T: Default`.
+impl<T> Default for Translation<T> {
+    fn default() -> Self {
+        Translation {
+            indonesian: None,
+            danish: None,
+            german: None,
+            english_uk: None,
+            english_us: None,
+            spanish: None,
+            spanish_latam: None,
+            french: None,
+            croatian: None,
+            italian: None,
+            lithuanian: None,
+            hungarian: None,
+            dutch: None,
+            norwegian: None,
+            polish: None,
+            portuguese_brazilian: None,
+            romanian_romania: None,
+            finnish: None,
+            swedish: None,
+            vietnamese: None,
+            turkish: None,
+            czech: None,
+            greek: None,
+            bulgarian: None,
+            russian: None,
+            ukrainian: None,
+            hindi: None,
+            thai: None,
+            chinese_china: None,
+            japanese: None,
+            chinese_taiwan: None,
+            korean: None,
+        }
+    }
 }
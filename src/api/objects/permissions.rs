@@ -5,7 +5,7 @@ use crate::flags;
 
 use super::{application::ApplicationId, guild::IntegrationId, ImageHash};
 
-flags!(permissions: i64 {
+flags!(permissions: i64 as String {
     CreateInstantInvite = 1 << 0,
     KickMembers = 1 << 1,
     BanMembers = 1 << 2,
@@ -63,6 +63,7 @@ pub use permissions::Flags as Permissions;
 pub struct Role {
     pub id: RoleId,
     pub name: String,
+    #[cfg_attr(feature = "serde", serde(with = "rgb"))]
     pub color: Rgb,
     pub hoist: bool,
     #[cfg_attr(feature = "serde", serde(default))]
@@ -83,11 +84,61 @@ pub struct Role {
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub struct RoleId(pub String);
 
+/// Discord sends colors as an integer (eg, `0x5865F2`), with `0` meaning no color,
+/// so fields should be (de)serialized with `rgb`.
 #[cfg_attr(feature = "clone", derive(Clone))]
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub struct Rgb(pub u8, pub u8, pub u8);
 
+impl From<u32> for Rgb {
+    fn from(value: u32) -> Self {
+        let [_, red, green, blue] = value.to_be_bytes();
+        Rgb(red, green, blue)
+    }
+}
+
+impl From<&Rgb> for u32 {
+    fn from(value: &Rgb) -> Self {
+        u32::from_be_bytes([0, value.0, value.1, value.2])
+    }
+}
+
+/// (De)serializes an `Rgb` as an integer.
+#[cfg(feature = "serde")]
+pub mod rgb {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::Rgb;
+
+    pub fn serialize<S: Serializer>(rgb: &Rgb, serializer: S) -> Result<S::Ok, S::Error> {
+        u32::from(rgb).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Rgb, D::Error> {
+        Ok(Rgb::from(u32::deserialize(deserializer)?))
+    }
+
+    pub mod option {
+        use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+        use super::Rgb;
+
+        pub fn serialize<S: Serializer>(
+            rgb: &Option<Rgb>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            rgb.as_ref().map(u32::from).serialize(serializer)
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Option<Rgb>, D::Error> {
+            Ok(Option::<u32>::deserialize(deserializer)?.map(Rgb::from))
+        }
+    }
+}
+
 /// Discord docs: https://discord.com/developers/docs/topics/permissions#role-object-role-tags-structure
 #[cfg_attr(feature = "clone", derive(Clone))]
 #[cfg_attr(feature = "debug", derive(Debug))]
@@ -97,24 +148,39 @@ pub struct RoleTags {
     pub bot_id: Option<ApplicationId>,
     #[cfg_attr(feature = "serde", serde(default))]
     pub integration_id: Option<IntegrationId>,
-    #[cfg_attr(feature = "serde", serde(default))]
+    #[cfg_attr(feature = "serde", serde(default, with = "present"))]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "std::ops::Not::not"))]
     pub premium_subscriber: bool,
     #[cfg_attr(feature = "serde", serde(default))]
     pub subscription_listing_id: Option<String>,
-    #[cfg_attr(feature = "serde", serde(default))]
+    #[cfg_attr(feature = "serde", serde(default, with = "present"))]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "std::ops::Not::not"))]
     pub available_for_purchase: bool,
-    #[cfg_attr(feature = "serde", serde(default))]
+    #[cfg_attr(feature = "serde", serde(default, with = "present"))]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "std::ops::Not::not"))]
     pub guild_connections: bool,
 }
 
-/// Discord docs: https://discord.com/developers/docs/topics/permissions#role-object-role-flags
-#[cfg_attr(feature = "clone", derive(Clone))]
-#[cfg_attr(feature = "debug", derive(Debug))]
-#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
-pub enum RoleFlags {
-    InPrompt = 1 << 0,
+/// Role tags that are `true` are sent as `null`, and left out when `false`.
+#[cfg(feature = "serde")]
+mod present {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(_: &bool, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_unit()
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<bool, D::Error> {
+        Option::<()>::deserialize(deserializer)?;
+        Ok(true)
+    }
 }
 
+flags!(role_flags: u8 {
+    InPrompt = 1 << 0,
+});
+pub use role_flags::Flags as RoleFlags;
+
 #[cfg(feature = "serde")]
 #[test]
 fn permissions() {
@@ -133,8 +199,31 @@ fn permissions() {
     ];
     for (expected_serialization, permissions) in permissions_to_check {
         let serialized = serde_json::to_string(&permissions).unwrap();
-        assert_eq!(expected_serialization, serialized);
+        assert_eq!(format!(r#""{expected_serialization}""#), serialized);
         let deserialized = serde_json::from_str(&serialized).unwrap();
         assert_eq!(permissions, deserialized);
+        let deserialized = serde_json::from_str(expected_serialization).unwrap();
+        assert_eq!(permissions, deserialized);
     }
 }
+
+#[cfg(feature = "serde")]
+#[test]
+fn role() {
+    let role: Role = serde_json::from_str(
+        r#"{"id":"1234","name":"Booster","color":16023551,"hoist":true,"icon":null,"unicode_emoji":null,"position":3,"permissions":"0","managed":true,"mentionable":false,"tags":{"premium_subscriber":null},"flags":0}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        (0xF4, 0x7F, 0xFF),
+        (role.color.0, role.color.1, role.color.2)
+    );
+    let tags = role.tags.as_ref().unwrap();
+    assert!(tags.premium_subscriber);
+    assert!(!tags.guild_connections);
+
+    assert_eq!(
+        r#"{"bot_id":null,"integration_id":null,"premium_subscriber":null,"subscription_listing_id":null}"#,
+        serde_json::to_string(tags).unwrap()
+    );
+}
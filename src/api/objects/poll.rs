@@ -1,22 +1,62 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "serde")]
+use serde_repr::{Deserialize_repr, Serialize_repr};
+#[cfg(feature = "serde")]
+use time::serde::iso8601;
+use time::OffsetDateTime;
+
+use super::emoji::Emoji;
 
 /// Discord docs: https://discord.com/developers/docs/resources/poll#poll-object
 #[cfg_attr(feature = "clone", derive(Clone))]
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
-pub struct Poll {}
+pub struct Poll {
+    /// Only `text` is supported.
+    pub question: PollMedia,
+    pub answers: Vec<PollAnswer>,
+    /// `None` for polls that don't expire.
+    #[cfg_attr(feature = "serde", serde(default, with = "iso8601::option"))]
+    pub expiry: Option<OffsetDateTime>,
+    pub allow_multiselect: bool,
+    pub layout_type: LayoutType,
+    /// Left out when the results haven't been fetched yet.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub results: Option<PollAnswers>,
+}
 
 /// Discord docs: https://discord.com/developers/docs/resources/poll#poll-create-request-object
 #[cfg_attr(feature = "clone", derive(Clone))]
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
-pub struct PollCreateRequest {}
+pub struct PollCreateRequest {
+    /// Only `text` is supported.
+    pub question: PollMedia,
+    pub answers: Vec<PollAnswer>,
+    /// Hours the poll is open for, up to 768 (32 days). Defaults to 24.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub duration: Option<u32>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub allow_multiselect: Option<bool>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub layout_type: Option<LayoutType>,
+}
 
 /// Discord docs: https://discord.com/developers/docs/resources/poll#layout-type
 #[cfg_attr(feature = "clone", derive(Clone))]
 #[cfg_attr(feature = "debug", derive(Debug))]
-#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "serde", derive(Deserialize_repr, Serialize_repr))]
+#[cfg_attr(feature = "serde", repr(u8))]
 pub enum LayoutType {
     Default = 1,
 }
@@ -25,16 +65,53 @@ pub enum LayoutType {
 #[cfg_attr(feature = "clone", derive(Clone))]
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
-pub struct PollMedia {}
+pub struct PollMedia {
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub text: Option<String>,
+    /// Only `id` is used for custom emoji, and only `name` for unicode emoji.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub emoji: Option<Emoji>,
+}
 
 /// Discord docs: https://discord.com/developers/docs/resources/poll#poll-answer-object
 #[cfg_attr(feature = "clone", derive(Clone))]
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
-pub struct PollAnswer {}
+pub struct PollAnswer {
+    /// Assigned by Discord, so left out when creating a poll.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub answer_id: Option<u64>,
+    pub poll_media: PollMedia,
+}
 
 /// Discord docs: https://discord.com/developers/docs/resources/poll#poll-results-object
 #[cfg_attr(feature = "clone", derive(Clone))]
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
-pub struct PollAnswers {}
+pub struct PollAnswers {
+    /// Whether the votes have been precisely counted.
+    pub is_finalized: bool,
+    /// Answers without any votes are left out.
+    pub answer_counts: Vec<PollAnswerCount>,
+}
+
+/// Discord docs: https://discord.com/developers/docs/resources/poll#poll-results-object-poll-answer-count-object-structure
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct PollAnswerCount {
+    /// `answer_id` of the answer.
+    pub id: u64,
+    pub count: u32,
+    /// Whether the current user voted for the answer.
+    pub me_voted: bool,
+}
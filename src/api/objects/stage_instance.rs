@@ -1,8 +1,37 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "serde")]
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+use super::{channel::ChannelId, guild::GuildId, guild_scheduled_event::GuildScheduledEventId};
 
 /// Discord docs: https://discord.com/developers/docs/resources/stage-instance#stage-instance-object
 #[cfg_attr(feature = "clone", derive(Clone))]
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
-pub struct StageInstance {}
+pub struct StageInstance {
+    pub id: StageInstanceId,
+    pub guild_id: GuildId,
+    pub channel_id: ChannelId,
+    pub topic: String,
+    pub privacy_level: PrivacyLevel,
+    /// Deprecated.
+    pub discoverable_disabled: bool,
+    pub guild_scheduled_event_id: Option<GuildScheduledEventId>,
+}
+
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct StageInstanceId(pub String);
+
+/// Discord docs: https://discord.com/developers/docs/resources/stage-instance#stage-instance-object-privacy-level
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize_repr, Serialize_repr))]
+#[cfg_attr(feature = "serde", repr(u8))]
+pub enum PrivacyLevel {
+    /// Deprecated.
+    Public = 1,
+    GuildOnly = 2,
+}
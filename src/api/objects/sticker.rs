@@ -1,20 +1,108 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "serde")]
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+use super::{guild::GuildId, user::User};
+
+/// Discord docs: https://discord.com/developers/docs/resources/sticker#sticker-object-sticker-structure
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct Sticker {
+    pub id: StickerId,
+    /// Pack of a `Standard` sticker.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub pack_id: Option<StickerPackId>,
+    pub name: String,
+    pub description: Option<String>,
+    /// Autocomplete/suggestion tags, comma separated for `Standard` stickers.
+    pub tags: String,
+    pub r#type: StickerType,
+    pub format_type: StickerFormatType,
+    /// Whether a `Guild` sticker can be used, which may be `false` after losing boosts.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub available: Option<bool>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub guild_id: Option<GuildId>,
+    /// User that uploaded a `Guild` sticker, with the `MANAGE_GUILD_EXPRESSIONS` permission.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub user: Option<User>,
+    /// Position of a `Standard` sticker in its pack.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub sort_value: Option<u32>,
+}
 
-/// Discord docs: https://discord.com/developers/docs/resources/sticker#sticker-resource
 #[cfg_attr(feature = "clone", derive(Clone))]
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
-pub struct Sticker {}
+pub struct StickerId(pub String);
+
+/// Discord docs: https://discord.com/developers/docs/resources/sticker#sticker-object-sticker-types
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize_repr, Serialize_repr))]
+#[cfg_attr(feature = "serde", repr(u8))]
+pub enum StickerType {
+    /// Official sticker in a pack.
+    Standard = 1,
+    /// Uploaded to a guild.
+    Guild = 2,
+}
+
+/// Discord docs: https://discord.com/developers/docs/resources/sticker#sticker-object-sticker-format-types
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize_repr, Serialize_repr))]
+#[cfg_attr(feature = "serde", repr(u8))]
+pub enum StickerFormatType {
+    Png = 1,
+    Apng = 2,
+    Lottie = 3,
+    Gif = 4,
+}
 
 /// Discord docs: https://discord.com/developers/docs/resources/sticker#sticker-item-object
 #[cfg_attr(feature = "clone", derive(Clone))]
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
-pub struct StickerItem {}
+pub struct StickerItem {
+    pub id: StickerId,
+    pub name: String,
+    pub format_type: StickerFormatType,
+}
 
 /// Discord docs: https://discord.com/developers/docs/resources/sticker#sticker-pack-object
 #[cfg_attr(feature = "clone", derive(Clone))]
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub struct StickerPack {}
+
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct StickerPackId(pub String);
+
+#[cfg(feature = "serde")]
+#[test]
+fn sticker() {
+    let sticker: Sticker = serde_json::from_str(
+        r#"{
+            "id": "749054660769218631",
+            "name": "Wave",
+            "tags": "wumpus, hello, sup, hi, oi, heyo, heya, yo, greetings",
+            "type": 1,
+            "format_type": 3,
+            "description": "Wumpus waves hello",
+            "asset": "",
+            "pack_id": "847199849233514549",
+            "sort_value": 12
+        }"#,
+    )
+    .unwrap();
+
+    assert!(matches!(sticker.r#type, StickerType::Standard));
+    assert!(matches!(sticker.format_type, StickerFormatType::Lottie));
+    assert_eq!(Some(12), sticker.sort_value);
+    assert!(sticker.guild_id.is_none());
+}
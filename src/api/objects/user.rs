@@ -1,5 +1,11 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "serde")]
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+use crate::flags;
+
+use super::{application::SkuId, ImageHash};
 
 #[cfg_attr(feature = "clone", derive(Clone))]
 #[cfg_attr(feature = "debug", derive(Debug))]
@@ -10,7 +16,69 @@ pub struct UserId(pub String);
 #[cfg_attr(feature = "clone", derive(Clone))]
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
-pub struct User {}
+pub struct User {
+    pub id: UserId,
+    pub username: String,
+    /// `"0"` for users that have migrated to unique usernames.
+    pub discriminator: String,
+    pub global_name: Option<String>,
+    pub avatar: Option<ImageHash>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub bot: Option<bool>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub system: Option<bool>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub mfa_enabled: Option<bool>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub banner: Option<ImageHash>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub accent_color: Option<u32>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub locale: Option<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub verified: Option<bool>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub email: Option<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub flags: Option<UserFlags>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub premium_type: Option<PremiumType>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub public_flags: Option<UserFlags>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub avatar_decoration_data: Option<AvatarDecorationData>,
+}
+
+flags!(user_flags: i32 {
+    Staff = 1 << 0,
+    Partner = 1 << 1,
+    Hypesquad = 1 << 2,
+    BugHunterLevel1 = 1 << 3,
+    HypesquadOnlineHouse1 = 1 << 6,
+    HypesquadOnlineHouse2 = 1 << 7,
+    HypesquadOnlineHouse3 = 1 << 8,
+    PremiumEarlySupporter = 1 << 9,
+    TeamPseudoUser = 1 << 10,
+    BugHunterLevel2 = 1 << 14,
+    VerifiedBot = 1 << 16,
+    VerifiedDeveloper = 1 << 17,
+    CertifiedModerator = 1 << 18,
+    BotHttpInteractions = 1 << 19,
+    ActiveDeveloper = 1 << 22,
+});
+pub use user_flags::Flags as UserFlags;
+
+/// Discord docs: https://discord.com/developers/docs/resources/user#user-object-premium-types
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize_repr, Serialize_repr))]
+#[cfg_attr(feature = "serde", repr(u8))]
+pub enum PremiumType {
+    None = 0,
+    NitroClassic = 1,
+    Nitro = 2,
+    NitroBasic = 3,
+}
 
 #[cfg_attr(feature = "clone", derive(Clone))]
 #[cfg_attr(feature = "debug", derive(Debug))]
@@ -21,7 +89,10 @@ pub struct UnavailableUser {}
 #[cfg_attr(feature = "clone", derive(Clone))]
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
-pub struct AvatarDecorationData {}
+pub struct AvatarDecorationData {
+    pub asset: ImageHash,
+    pub sku_id: SkuId,
+}
 
 /// Discord docs: https://discord.com/developers/docs/resources/user#connection-object
 #[cfg_attr(feature = "clone", derive(Clone))]
@@ -1,18 +1,105 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "serde")]
+use serde_repr::{Deserialize_repr, Serialize_repr};
+use url::Url;
+
+use super::{
+    application::ApplicationId, channel::ChannelId, guild::GuildId, user::User, ImageHash,
+};
 
 /// Discord docs: https://discord.com/developers/docs/resources/webhook#webhook-object-webhook-structure
 #[cfg_attr(feature = "clone", derive(Clone))]
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
-pub struct Webhook {}
+pub struct Webhook {
+    pub id: WebhookId,
+    pub r#type: WebhookType,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub guild_id: Option<GuildId>,
+    pub channel_id: Option<ChannelId>,
+    /// User that created the webhook, left out when fetched with its token.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub user: Option<User>,
+    pub name: Option<String>,
+    pub avatar: Option<ImageHash>,
+    /// Only for `Incoming` webhooks.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub token: Option<String>,
+    /// Application that created the webhook, for `Application` webhooks.
+    pub application_id: Option<ApplicationId>,
+    /// Guild of the channel being followed, for `ChannelFollower` webhooks.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub source_guild: Option<WebhookSourceGuild>,
+    /// Channel being followed, for `ChannelFollower` webhooks.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub source_channel: Option<WebhookSourceChannel>,
+    /// URL for executing the webhook, only for `Incoming` webhooks.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub url: Option<Url>,
+}
 
-/// Discord docs: https://discord.com/developers/docs/resources/webhook#webhook-object-webhook-types
 #[cfg_attr(feature = "clone", derive(Clone))]
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct WebhookId(pub String);
+
+/// Discord docs: https://discord.com/developers/docs/resources/webhook#webhook-object-webhook-types
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize_repr, Serialize_repr))]
+#[cfg_attr(feature = "serde", repr(u8))]
 pub enum WebhookType {
     Incoming = 1,
     ChannelFollower = 2,
     Application = 3,
 }
+
+/// Partial guild for the `source_guild` of a webhook.
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct WebhookSourceGuild {
+    pub id: GuildId,
+    pub name: String,
+    pub icon: Option<ImageHash>,
+}
+
+/// Partial channel for the `source_channel` of a webhook.
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct WebhookSourceChannel {
+    pub id: ChannelId,
+    pub name: String,
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn webhook() {
+    let webhook: Webhook = serde_json::from_str(
+        r#"{
+            "type": 2,
+            "id": "752831914402115456",
+            "name": "Announcements",
+            "avatar": null,
+            "channel_id": "561885260615255432",
+            "guild_id": "56188498421443265",
+            "application_id": null,
+            "source_guild": {
+                "id": "56188498421476534",
+                "name": "Guildy name",
+                "icon": "bf7b9d23ab8a82a0c9f2e1f3e8a1a9e1"
+            },
+            "source_channel": {
+                "id": "5618852344134324",
+                "name": "announcements"
+            }
+        }"#,
+    )
+    .unwrap();
+
+    assert!(matches!(webhook.r#type, WebhookType::ChannelFollower));
+    assert_eq!("announcements", webhook.source_channel.unwrap().name);
+    assert!(webhook.token.is_none());
+}
@@ -1,31 +1,132 @@
+use std::fmt::{self, Display, Formatter};
+
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "serde")]
+use serde_repr::{Deserialize_repr, Serialize_repr};
+use time::OffsetDateTime;
+use url::Url;
 
-use super::Unimplemented;
+#[cfg(feature = "serde")]
+use crate::timestamp::unix_millis;
+use crate::{api::objects::application::ApplicationId, flags};
 
+/// Discord docs: https://discord.com/developers/docs/topics/gateway-events#activity-object
 #[cfg_attr(feature = "clone", derive(Clone))]
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub struct Activity {
     pub name: String,
-    pub r#type: usize,
+    pub r#type: ActivityType,
     pub url: Option<String>,
-    // #[cfg_attr(feature = "serde", serde(with = "time::serde::timestamp::milliseconds::option"))]
-    // i128 is broken with this
-    pub created_at: usize,
+    #[cfg_attr(feature = "serde", serde(with = "unix_millis"))]
+    pub created_at: OffsetDateTime,
     pub timestamps: Option<ActivityTimestamps>,
-    pub application_id: Option<String>,
+    pub application_id: Option<ApplicationId>,
     pub details: Option<String>,
     pub state: Option<String>,
     pub emoji: Option<ActivityEmoji>,
-    pub party: Option<Unimplemented>,
-    pub assets: Option<Unimplemented>,
-    pub secrets: Option<Unimplemented>,
+    pub party: Option<ActivityParty>,
+    pub assets: Option<ActivityAssets>,
+    pub secrets: Option<ActivitySecrets>,
     pub instance: Option<bool>,
-    pub flags: Option<usize>,
-    pub buttons: Option<Vec<Unimplemented>>,
+    pub flags: Option<ActivityFlags>,
+    pub buttons: Option<Vec<ActivityButton>>,
 }
 
+impl Activity {
+    /// Checks that only the fields bots are allowed to set (`name`, `state`, `type`, and `url`) are set,
+    /// and that `url` is only set for a Twitch or YouTube stream.
+    ///
+    /// Discord docs: https://discord.com/developers/docs/topics/gateway-events#activity-object-activity-structure
+    pub fn validate_for_bot(&self) -> Result<(), BotActivityError> {
+        let receive_only_fields = [
+            ("timestamps", self.timestamps.is_some()),
+            ("application_id", self.application_id.is_some()),
+            ("details", self.details.is_some()),
+            ("emoji", self.emoji.is_some()),
+            ("party", self.party.is_some()),
+            ("assets", self.assets.is_some()),
+            ("secrets", self.secrets.is_some()),
+            ("instance", self.instance.is_some()),
+            ("flags", self.flags.is_some()),
+            ("buttons", self.buttons.is_some()),
+        ];
+        if let Some((field, _)) = receive_only_fields.into_iter().find(|(_, is_set)| *is_set) {
+            return Err(BotActivityError::FieldNotAllowed(field));
+        }
+
+        let Some(url) = &self.url else {
+            return Ok(());
+        };
+        if !matches!(self.r#type, ActivityType::Streaming) {
+            return Err(BotActivityError::UrlWithoutStreaming);
+        }
+        // Subdomains are allowed too, such as `m.twitch.tv` and `www.youtube.com`.
+        let is_stream_host = |host: &str| {
+            ["twitch.tv", "youtube.com", "youtu.be"]
+                .iter()
+                .any(|domain| {
+                    host.strip_suffix(domain)
+                        .is_some_and(|subdomain| subdomain.is_empty() || subdomain.ends_with('.'))
+                })
+        };
+        let host = Url::parse(url)
+            .ok()
+            .and_then(|url| url.host_str().map(str::to_owned));
+        match host {
+            Some(host) if is_stream_host(&host) => Ok(()),
+            _ => Err(BotActivityError::InvalidStreamUrl(url.clone())),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum BotActivityError {
+    /// The named field can only be received, not sent by a bot.
+    FieldNotAllowed(&'static str),
+    /// `url` is only used for `ActivityType::Streaming`.
+    UrlWithoutStreaming,
+    /// `url` must link to a Twitch or YouTube stream.
+    InvalidStreamUrl(String),
+}
+
+impl Display for BotActivityError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            BotActivityError::FieldNotAllowed(field) => {
+                write!(
+                    f,
+                    "Bots are not allowed to set the `{field}` field of an activity"
+                )
+            }
+            BotActivityError::UrlWithoutStreaming => {
+                write!(f, "`url` can only be set for a streaming activity")
+            }
+            BotActivityError::InvalidStreamUrl(url) => {
+                write!(f, "`{url}` is not a Twitch or YouTube URL")
+            }
+        }
+    }
+}
+
+impl std::error::Error for BotActivityError {}
+
+/// Discord docs: https://discord.com/developers/docs/topics/gateway-events#activity-object-activity-types
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize_repr, Serialize_repr))]
+#[cfg_attr(feature = "serde", repr(u8))]
+pub enum ActivityType {
+    Playing = 0,
+    Streaming = 1,
+    Listening = 2,
+    Watching = 3,
+    Custom = 4,
+    Competing = 5,
+}
+
+/// Discord docs: https://discord.com/developers/docs/topics/gateway-events#activity-object-activity-emoji
 #[cfg_attr(feature = "clone", derive(Clone))]
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
@@ -35,16 +136,253 @@ pub struct ActivityEmoji {
     pub animated: Option<bool>,
 }
 
+/// Discord docs: https://discord.com/developers/docs/topics/gateway-events#activity-object-activity-timestamps
 #[cfg_attr(feature = "clone", derive(Clone))]
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub struct ActivityTimestamps {
     #[cfg_attr(feature = "serde", serde(default))]
-    // #[cfg_attr(feature = "serde", serde(with = "time::serde::timestamp::milliseconds::option"))]
-    // i128 is broken with this
-    pub start: Option<usize>,
+    #[cfg_attr(feature = "serde", serde(with = "unix_millis::option"))]
+    pub start: Option<OffsetDateTime>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    #[cfg_attr(feature = "serde", serde(with = "unix_millis::option"))]
+    pub end: Option<OffsetDateTime>,
+}
+
+/// Discord docs: https://discord.com/developers/docs/topics/gateway-events#activity-object-activity-party
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct ActivityParty {
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub id: Option<String>,
+    /// Current size and max size of the party.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub size: Option<(u32, u32)>,
+}
+
+/// Discord docs: https://discord.com/developers/docs/topics/gateway-events#activity-object-activity-assets
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct ActivityAssets {
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub large_image: Option<ActivityAssetImage>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub large_text: Option<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub small_image: Option<ActivityAssetImage>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub small_text: Option<String>,
+}
+
+/// Discord docs: https://discord.com/developers/docs/topics/gateway-events#activity-object-activity-asset-image
+#[derive(Eq, PartialEq)]
+#[cfg_attr(any(feature = "clone", feature = "serde"), derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "serde", serde(from = "String"))]
+#[cfg_attr(feature = "serde", serde(into = "String"))]
+pub enum ActivityAssetImage {
+    /// ID of an asset uploaded to the activity's application.
+    Application(String),
+    /// Path of an image proxied through Discord's media proxy (`mp:{path}`),
+    /// i.e. `https://media.discordapp.net/{path}`.
+    MediaProxy(String),
+    /// Spotify album art ID (`spotify:{id}`).
+    Spotify(String),
+    /// Twitch username of a stream preview (`twitch:{username}`).
+    Twitch(String),
+    /// YouTube video ID of a thumbnail (`youtube:{id}`).
+    YouTube(String),
+}
+
+impl ActivityAssetImage {
+    const PREFIX_MEDIA_PROXY: &'static str = "mp:";
+    const PREFIX_SPOTIFY: &'static str = "spotify:";
+    const PREFIX_TWITCH: &'static str = "twitch:";
+    const PREFIX_YOUTUBE: &'static str = "youtube:";
+}
+
+impl From<String> for ActivityAssetImage {
+    fn from(value: String) -> Self {
+        if let Some(path) = value.strip_prefix(Self::PREFIX_MEDIA_PROXY) {
+            Self::MediaProxy(path.to_owned())
+        } else if let Some(id) = value.strip_prefix(Self::PREFIX_SPOTIFY) {
+            Self::Spotify(id.to_owned())
+        } else if let Some(username) = value.strip_prefix(Self::PREFIX_TWITCH) {
+            Self::Twitch(username.to_owned())
+        } else if let Some(id) = value.strip_prefix(Self::PREFIX_YOUTUBE) {
+            Self::YouTube(id.to_owned())
+        } else {
+            Self::Application(value)
+        }
+    }
+}
+
+impl From<ActivityAssetImage> for String {
+    fn from(value: ActivityAssetImage) -> Self {
+        match value {
+            ActivityAssetImage::Application(id) => id,
+            ActivityAssetImage::MediaProxy(path) => {
+                format!("{}{path}", ActivityAssetImage::PREFIX_MEDIA_PROXY)
+            }
+            ActivityAssetImage::Spotify(id) => {
+                format!("{}{id}", ActivityAssetImage::PREFIX_SPOTIFY)
+            }
+            ActivityAssetImage::Twitch(username) => {
+                format!("{}{username}", ActivityAssetImage::PREFIX_TWITCH)
+            }
+            ActivityAssetImage::YouTube(id) => {
+                format!("{}{id}", ActivityAssetImage::PREFIX_YOUTUBE)
+            }
+        }
+    }
+}
+
+/// Discord docs: https://discord.com/developers/docs/topics/gateway-events#activity-object-activity-secrets
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct ActivitySecrets {
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub join: Option<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub spectate: Option<String>,
     #[cfg_attr(feature = "serde", serde(default))]
-    // #[cfg_attr(feature = "serde", serde(with = "time::serde::timestamp::milliseconds::option"))]
-    // i128 is broken with this
-    pub end: Option<usize>,
+    pub r#match: Option<String>,
+}
+
+/// Discord docs: https://discord.com/developers/docs/topics/gateway-events#activity-object-activity-buttons
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "serde", serde(untagged))]
+pub enum ActivityButton {
+    /// Received over the gateway, where bots can only see the button's label.
+    Label(String),
+    Button {
+        label: String,
+        url: Url,
+    },
+}
+
+flags!(activity_flags: i32 {
+    Instance = 1 << 0,
+    Join = 1 << 1,
+    Spectate = 1 << 2,
+    JoinRequest = 1 << 3,
+    Sync = 1 << 4,
+    Play = 1 << 5,
+    PartyPrivacyFriends = 1 << 6,
+    PartyPrivacyVoiceChannel = 1 << 7,
+    Embedded = 1 << 8,
+});
+pub use activity_flags::Flags as ActivityFlags;
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_activity() {
+    use std::assert_matches::assert_matches;
+
+    let json = r#"{
+        "name": "Rocket League",
+        "type": 0,
+        "created_at": 1718313600000,
+        "application_id": "379286085710381999",
+        "state": "In a Match",
+        "details": "Ranked Duos: 2-1",
+        "timestamps": {
+            "start": 1718313000000
+        },
+        "party": {
+            "id": "9dd6594e-81b3-49f6-a6b5-a679e6a060d3",
+            "size": [2, 2]
+        },
+        "assets": {
+            "large_image": "351371005538729000",
+            "large_text": "DFH Stadium",
+            "small_image": "mp:external/abc/https/example.com/image.png",
+            "small_text": "Silver III"
+        },
+        "secrets": {
+            "join": "025ed05c71f639de8bfaa0d679d7c94b2fdce12f"
+        },
+        "flags": 3,
+        "buttons": ["Watch"]
+    }"#;
+    let activity: Activity = serde_json::from_str(json).expect("Deserializing should succeed");
+    assert_matches!(activity.r#type, ActivityType::Playing);
+    assert_matches!(
+        activity.validate_for_bot(),
+        Err(BotActivityError::FieldNotAllowed("timestamps"))
+    );
+    assert_eq!(Some((2, 2)), activity.party.and_then(|party| party.size));
+    let assets = activity.assets.expect("assets should be set");
+    assert_eq!(
+        Some(ActivityAssetImage::Application(String::from(
+            "351371005538729000"
+        ))),
+        assets.large_image
+    );
+    assert_eq!(
+        Some(ActivityAssetImage::MediaProxy(String::from(
+            "external/abc/https/example.com/image.png"
+        ))),
+        assets.small_image
+    );
+    assert_eq!(
+        Some(flags!(activity_flags(Instance | Join))),
+        activity.flags
+    );
+    assert_matches!(
+        activity.buttons.as_deref(),
+        Some([ActivityButton::Label(_)])
+    );
+}
+
+#[test]
+fn test_validate_for_bot() {
+    use std::assert_matches::assert_matches;
+
+    let mut activity = Activity {
+        name: String::from("the stream"),
+        r#type: ActivityType::Streaming,
+        url: Some(String::from("https://www.twitch.tv/discord")),
+        created_at: OffsetDateTime::UNIX_EPOCH,
+        timestamps: None,
+        application_id: None,
+        details: None,
+        state: Some(String::from("Live")),
+        emoji: None,
+        party: None,
+        assets: None,
+        secrets: None,
+        instance: None,
+        flags: None,
+        buttons: None,
+    };
+    assert_matches!(activity.validate_for_bot(), Ok(()));
+
+    activity.url = Some(String::from("https://m.twitch.tv/discord"));
+    assert_matches!(activity.validate_for_bot(), Ok(()));
+    activity.url = Some(String::from("https://youtu.be/dQw4w9WgXcQ"));
+    assert_matches!(activity.validate_for_bot(), Ok(()));
+
+    activity.url = Some(String::from("https://example.com/discord"));
+    assert_matches!(
+        activity.validate_for_bot(),
+        Err(BotActivityError::InvalidStreamUrl(_))
+    );
+    activity.url = Some(String::from("https://nottwitch.tv/discord"));
+    assert_matches!(
+        activity.validate_for_bot(),
+        Err(BotActivityError::InvalidStreamUrl(_))
+    );
+
+    activity.r#type = ActivityType::Playing;
+    assert_matches!(
+        activity.validate_for_bot(),
+        Err(BotActivityError::UrlWithoutStreaming)
+    );
 }
@@ -0,0 +1,81 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::api::objects::{
+    auto_moderation::{AutoModerationAction, AutoModerationRuleId, TriggerType},
+    channel::{ChannelId, MessageId},
+    guild::GuildId,
+    user::UserId,
+};
+
+/// Discord docs: https://discord.com/developers/docs/topics/gateway-events#auto-moderation-action-execution
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct AutoModerationActionExecution {
+    pub guild_id: GuildId,
+    pub action: AutoModerationAction,
+    pub rule_id: AutoModerationRuleId,
+    pub rule_trigger_type: TriggerType,
+    pub user_id: UserId,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub channel_id: Option<ChannelId>,
+    /// Not present if the message was blocked by the rule.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub message_id: Option<MessageId>,
+    /// Not present if the rule has no `SendAlertMessage` action.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub alert_system_message_id: Option<MessageId>,
+    /// Empty without the `MessageContent` intent.
+    pub content: String,
+    pub matched_keyword: Option<String>,
+    /// Empty or `None` without the `MessageContent` intent.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub matched_content: Option<String>,
+}
+
+/// JSON adapted from https://discord.com/developers/docs/topics/gateway-events#auto-moderation-action-execution
+#[cfg(feature = "serde")]
+#[test]
+fn test_auto_moderation_action_execution() {
+    use super::{Event, EventPayload};
+    use crate::api::objects::auto_moderation::ActionType;
+    use std::assert_matches::assert_matches;
+
+    let json = r#"{
+        "t": "AUTO_MODERATION_ACTION_EXECUTION",
+        "s": 7,
+        "op": 0,
+        "d": {
+            "guild_id": "2345678901234567890",
+            "action": {
+                "type": 3,
+                "metadata": {
+                    "duration_seconds": 60
+                }
+            },
+            "rule_id": "4567890123456789012",
+            "rule_trigger_type": 1,
+            "user_id": "123456789012345678",
+            "channel_id": "3456789012345678901",
+            "message_id": "5678901234567890123",
+            "content": "some bad word",
+            "matched_keyword": "bad*",
+            "matched_content": "bad"
+        }
+    }"#;
+    let deserializer = &mut serde_json::Deserializer::from_str(json);
+    let event = serde_path_to_error::deserialize::<_, EventPayload>(deserializer)
+        .expect("Deserializing should succeed");
+    let EventPayload::Dispatch(_, Event::AutoModerationActionExecution(execution)) = event else {
+        panic!("Expected an `AUTO_MODERATION_ACTION_EXECUTION` dispatch");
+    };
+    assert_matches!(execution.action.r#type, ActionType::Timeout);
+    assert_matches!(execution.rule_trigger_type, TriggerType::Keyword);
+    assert_eq!(Some("bad*"), execution.matched_keyword.as_deref());
+    assert_eq!(Some("bad"), execution.matched_content.as_deref());
+    assert_eq!(
+        Some(60),
+        execution.action.metadata.and_then(|m| m.duration_seconds)
+    );
+}
@@ -13,6 +13,8 @@ use crate::api::objects::{
 
 use super::{presence::PresenceUpdate, voice::VoiceState};
 
+// Guilds are usually available, so boxing them would only add an allocation.
+#[allow(clippy::large_enum_variant)]
 #[cfg_attr(feature = "clone", derive(Clone))]
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
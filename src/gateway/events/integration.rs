@@ -0,0 +1,38 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::api::objects::{
+    application::ApplicationId,
+    guild::{GuildId, Integration, IntegrationId},
+};
+
+/// Discord docs: https://discord.com/developers/docs/topics/gateway-events#integration-create
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct IntegrationCreate {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub integration: Integration,
+    pub guild_id: GuildId,
+}
+
+/// Discord docs: https://discord.com/developers/docs/topics/gateway-events#integration-update
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct IntegrationUpdate {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub integration: Integration,
+    pub guild_id: GuildId,
+}
+
+/// Discord docs: https://discord.com/developers/docs/topics/gateway-events#integration-delete
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct IntegrationDelete {
+    pub id: IntegrationId,
+    pub guild_id: GuildId,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub application_id: Option<ApplicationId>,
+}
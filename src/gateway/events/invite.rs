@@ -0,0 +1,106 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "serde")]
+use time::serde::iso8601;
+use time::OffsetDateTime;
+
+use crate::api::objects::{
+    channel::ChannelId, guild::GuildId, invite::InviteTargetType, user::User,
+};
+
+use super::Unimplemented;
+
+/// Discord docs: https://discord.com/developers/docs/topics/gateway-events#invite-create
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct InviteCreate {
+    pub channel_id: ChannelId,
+    pub code: String,
+    #[cfg_attr(feature = "serde", serde(with = "iso8601"))]
+    pub created_at: OffsetDateTime,
+    /// When the invite expires, or `None` if it never does.
+    #[cfg_attr(feature = "serde", serde(default, with = "iso8601::option"))]
+    pub expires_at: Option<OffsetDateTime>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub guild_id: Option<GuildId>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub inviter: Option<User>,
+    /// How long the invite is valid for (in seconds), or `0` if it never expires.
+    pub max_age: u64,
+    /// Maximum number of times the invite can be used, or `0` if unlimited.
+    pub max_uses: u64,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub target_type: Option<InviteTargetType>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub target_user: Option<User>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub target_application: Option<Unimplemented>,
+    /// Whether the invite only grants temporary membership.
+    pub temporary: bool,
+    /// Always `0` for a newly created invite.
+    pub uses: u64,
+}
+
+/// Discord docs: https://discord.com/developers/docs/topics/gateway-events#invite-delete
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct InviteDelete {
+    pub channel_id: ChannelId,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub guild_id: Option<GuildId>,
+    pub code: String,
+}
+
+/// Taken from a gateway response on 2024-06-12 with IDs/codes randomized
+#[cfg(feature = "serde")]
+#[test]
+fn test_invite_create() {
+    use super::{Event, EventPayload};
+    use std::assert_matches::assert_matches;
+
+    let json = r#"{
+        "t": "INVITE_CREATE",
+        "s": 5,
+        "op": 0,
+        "d": {
+            "uses": 0,
+            "temporary": false,
+            "max_uses": 10,
+            "max_age": 604800,
+            "inviter": {
+                "username": "foobar",
+                "public_flags": 0,
+                "id": "123456789012345678",
+                "global_name": null,
+                "discriminator": "0",
+                "avatar": null
+            },
+            "guild_id": "2345678901234567890",
+            "expires_at": "2024-06-19T18:27:45+00:00",
+            "created_at": "2024-06-12T18:27:45.123000+00:00",
+            "code": "aBcDeFgH",
+            "channel_id": "3456789012345678901"
+        }
+    }"#;
+    let deserializer = &mut serde_json::Deserializer::from_str(json);
+    let event = serde_path_to_error::deserialize::<_, EventPayload>(deserializer)
+        .expect("Deserializing should succeed");
+    let EventPayload::Dispatch(_, Event::InviteCreate(invite)) = event else {
+        panic!("Expected an `INVITE_CREATE` event");
+    };
+    assert_matches!(
+        invite,
+        InviteCreate {
+            max_uses: 10,
+            inviter: Some(_),
+            target_type: None,
+            ..
+        }
+    );
+    assert_eq!(
+        Some(OffsetDateTime::from_unix_timestamp(1718821665).unwrap()),
+        invite.expires_at
+    );
+}
@@ -0,0 +1 @@
+pub mod poll_vote;
@@ -0,0 +1,22 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::api::objects::{
+    channel::{ChannelId, MessageId},
+    guild::GuildId,
+    user::UserId,
+};
+
+/// Discord docs: https://discord.com/developers/docs/topics/gateway-events#message-poll-vote-add
+/// and https://discord.com/developers/docs/topics/gateway-events#message-poll-vote-remove
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct MessagePollVote {
+    pub user_id: UserId,
+    pub channel_id: ChannelId,
+    pub message_id: MessageId,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub guild_id: Option<GuildId>,
+    pub answer_id: u64,
+}
@@ -13,17 +13,38 @@ pub mod user;
 pub mod voice;
 pub mod webhooks;
 
-use self::{guild::GuildCreate, presence::PresenceUpdate, voice::VoiceState};
+use self::{
+    auto_moderation::AutoModerationActionExecution,
+    guild::GuildCreate,
+    integration::{IntegrationCreate, IntegrationDelete, IntegrationUpdate},
+    invite::{InviteCreate, InviteDelete},
+    message::poll_vote::MessagePollVote,
+    presence::PresenceUpdate,
+    voice::{VoiceServerUpdate, VoiceState},
+    webhooks::WebhooksUpdate,
+};
 use crate::{
-    api::objects::{application::ApplicationFlags, guild::UnavailableGuild, user::User},
+    api::objects::{
+        application::ApplicationFlags, application_command::GuildApplicationCommandPermissions,
+        auto_moderation::AutoModerationRule, entitlement::Entitlement, guild::UnavailableGuild,
+        stage_instance::StageInstance, user::User,
+    },
     flags,
 };
 #[cfg(feature = "serde")]
-use serde::{de, ser::SerializeStruct, Deserialize, Deserializer, Serialize};
+use serde::{
+    de::{
+        self, value::MapAccessDeserializer, DeserializeSeed, IntoDeserializer, MapAccess, Visitor,
+    },
+    ser::SerializeStruct,
+    Deserialize, Deserializer, Serialize,
+};
 #[cfg(feature = "serde")]
-use serde_json::{json, Value};
+use serde_json::Value;
 #[cfg(feature = "serde")]
 use serde_repr::{Deserialize_repr, Serialize_repr};
+#[cfg(feature = "serde")]
+use std::fmt;
 #[cfg(not(feature = "serde"))]
 use std::{any::Any, rc::Rc};
 
@@ -49,6 +70,8 @@ pub enum Opcode {
 // Could use `#[serde(tag = "op", content = "d")]` and `#[serde(rename = "2", skip_deserializing)]`
 // if https://github.com/serde-rs/serde/issues/745 was fixed :(
 // In the meantime, see `OpCode` for mapping of codes.
+// `Dispatch` is by far the most common payload, so boxing it would only add an allocation.
+#[allow(clippy::large_enum_variant)]
 #[cfg_attr(feature = "clone", derive(Clone))]
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub enum EventPayload {
@@ -119,44 +142,160 @@ impl Serialize for EventPayload {
 #[cfg(feature = "serde")]
 impl<'de> Deserialize<'de> for EventPayload {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
-        struct RawEventPayload {
-            #[serde(rename = "op")]
-            opcode: Opcode,
-            #[serde(rename = "s", default)]
-            sequence_number: Option<SequenceNumber>,
-            #[serde(rename = "d", default)]
-            data: Value,
-            #[serde(rename = "t", default)]
-            event_name: Value,
+        deserializer.deserialize_map(EventPayloadVisitor)
+    }
+}
+
+/// Deserializes `d` in place once `op` (and `t` for dispatches) are known, so errors keep the
+/// path to the invalid field. Discord sends those first, but `d` is buffered if it comes earlier.
+#[cfg(feature = "serde")]
+struct EventPayloadVisitor;
+
+#[cfg(feature = "serde")]
+impl<'de> Visitor<'de> for EventPayloadVisitor {
+    type Value = EventPayload;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a gateway payload")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<EventPayload, A::Error> {
+        let mut opcode: Option<Opcode> = None;
+        let mut sequence_number: Option<SequenceNumber> = None;
+        let mut event_name: Option<String> = None;
+        let mut data = None;
+        let mut buffered_data: Option<Value> = None;
+
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                EventPayload::FIELD_OPCODE => opcode = Some(map.next_value()?),
+                EventPayload::FIELD_SEQUENCE_NUMBER => sequence_number = map.next_value()?,
+                EventPayload::FIELD_EVENT_NAME => event_name = map.next_value()?,
+                EventPayload::FIELD_DATA => match &opcode {
+                    Some(Opcode::Dispatch) if event_name.is_none() => {
+                        buffered_data = Some(map.next_value()?);
+                    }
+                    Some(opcode) => {
+                        data = Some(map.next_value_seed(EventDataSeed {
+                            opcode,
+                            event_name: event_name.as_deref(),
+                        })?);
+                    }
+                    None => buffered_data = Some(map.next_value()?),
+                },
+                _ => {
+                    map.next_value::<de::IgnoredAny>()?;
+                }
+            }
         }
 
-        let raw_event = RawEventPayload::deserialize(deserializer)?;
-        match raw_event.opcode {
-            Opcode::Dispatch => Ok(EventPayload::Dispatch(
-                raw_event.sequence_number.ok_or(de::Error::custom(
-                    "No sequence number provided for a `Dispatch` event",
-                ))?,
-                serde_json::from_value(json!({
-                    Self::FIELD_DATA: raw_event.data,
-                    Self::FIELD_EVENT_NAME: raw_event.event_name
-                }))
-                .map_err(de::Error::custom)?,
-            )),
-            Opcode::Heartbeat => Ok(EventPayload::Heartbeat(raw_event.sequence_number)),
-            Opcode::Identify => Ok(EventPayload::Identify(
-                serde_json::from_value(raw_event.data).map_err(de::Error::custom)?,
-            )),
-            Opcode::PresenceUpdate => Ok(EventPayload::PresenceUpdate),
-            Opcode::VoiceStateUpdate => Ok(EventPayload::VoiceStateUpdate),
-            Opcode::Resume => Ok(EventPayload::Resume),
-            Opcode::Reconnect => Ok(EventPayload::Reconnect),
-            Opcode::RequestGuildMembers => Ok(EventPayload::RequestGuildMembers),
-            Opcode::InvalidSession => Ok(EventPayload::InvalidSession),
-            Opcode::Hello => Ok(EventPayload::Hello(
-                serde_json::from_value(raw_event.data).map_err(de::Error::custom)?,
-            )),
-            Opcode::HeartbeatAck => Ok(EventPayload::HeartbeatAck),
+        let opcode = opcode.ok_or_else(|| de::Error::missing_field(EventPayload::FIELD_OPCODE))?;
+        if matches!(opcode, Opcode::Dispatch) && sequence_number.is_none() {
+            return Err(de::Error::custom(
+                "No sequence number provided for a `Dispatch` event",
+            ));
+        }
+        let data = match data {
+            Some(data) => data,
+            None => EventDataSeed {
+                opcode: &opcode,
+                event_name: event_name.as_deref(),
+            }
+            .deserialize(buffered_data.unwrap_or_default())
+            .map_err(de::Error::custom)?,
+        };
+
+        Ok(match (opcode, data) {
+            (_, EventData::Dispatch(event)) => {
+                EventPayload::Dispatch(sequence_number.expect("checked above"), *event)
+            }
+            (_, EventData::Identify(identify)) => EventPayload::Identify(*identify),
+            (_, EventData::Hello(hello)) => EventPayload::Hello(hello),
+            (Opcode::Heartbeat, _) => EventPayload::Heartbeat(sequence_number),
+            (Opcode::PresenceUpdate, _) => EventPayload::PresenceUpdate,
+            (Opcode::VoiceStateUpdate, _) => EventPayload::VoiceStateUpdate,
+            (Opcode::Resume, _) => EventPayload::Resume,
+            (Opcode::Reconnect, _) => EventPayload::Reconnect,
+            (Opcode::RequestGuildMembers, _) => EventPayload::RequestGuildMembers,
+            (Opcode::InvalidSession, _) => EventPayload::InvalidSession,
+            (Opcode::HeartbeatAck, _) => EventPayload::HeartbeatAck,
+            (Opcode::Dispatch | Opcode::Identify | Opcode::Hello, EventData::Ignored) => {
+                unreachable!("`EventDataSeed` deserializes the data for these opcodes")
+            }
+        })
+    }
+}
+
+/// `d` of a payload, for the opcodes that have one.
+#[cfg(feature = "serde")]
+enum EventData {
+    Dispatch(Box<Event>),
+    Identify(Box<Identify>),
+    Hello(Hello),
+    Ignored,
+}
+
+#[cfg(feature = "serde")]
+struct EventDataSeed<'a> {
+    opcode: &'a Opcode,
+    event_name: Option<&'a str>,
+}
+
+#[cfg(feature = "serde")]
+impl<'de> DeserializeSeed<'de> for EventDataSeed<'_> {
+    type Value = EventData;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<EventData, D::Error> {
+        Ok(match self.opcode {
+            Opcode::Dispatch => EventData::Dispatch(Box::new(Event::deserialize(
+                MapAccessDeserializer::new(DispatchAccess {
+                    event_name: self.event_name,
+                    data: Some(deserializer),
+                }),
+            )?)),
+            Opcode::Identify => EventData::Identify(Box::new(Identify::deserialize(deserializer)?)),
+            Opcode::Hello => EventData::Hello(Hello::deserialize(deserializer)?),
+            _ => {
+                de::IgnoredAny::deserialize(deserializer)?;
+                EventData::Ignored
+            }
+        })
+    }
+}
+
+/// Feeds `t` and then `d` to `Event`, without buffering `d`.
+#[cfg(feature = "serde")]
+struct DispatchAccess<'a, D> {
+    event_name: Option<&'a str>,
+    data: Option<D>,
+}
+
+#[cfg(feature = "serde")]
+impl<'de, D: Deserializer<'de>> MapAccess<'de> for DispatchAccess<'_, D> {
+    type Error = D::Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, D::Error> {
+        let key = if self.event_name.is_some() {
+            EventPayload::FIELD_EVENT_NAME
+        } else if self.data.is_some() {
+            EventPayload::FIELD_DATA
+        } else {
+            return Ok(None);
+        };
+        seed.deserialize(key.into_deserializer()).map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, D::Error> {
+        match (self.event_name.take(), self.data.take()) {
+            (Some(event_name), data) => {
+                self.data = data;
+                seed.deserialize(event_name.into_deserializer())
+            }
+            (None, Some(data)) => seed.deserialize(data),
+            (None, None) => Err(de::Error::custom("No value left for the key")),
         }
     }
 }
@@ -174,12 +313,12 @@ pub struct SequenceNumber(pub u64);
 #[cfg_attr(feature = "serde", serde(rename_all = "SCREAMING_SNAKE_CASE"))]
 pub enum Event {
     Ready(Ready),
-    Resumed(Unimplemented),
-    ApplicationCommandPermissionsUpdate(Unimplemented),
-    AutoModerationRuleCreate(Unimplemented),
-    AutoModerationRuleUpdate(Unimplemented),
-    AutoModerationRuleDelete(Unimplemented),
-    AutoModerationActionExecution(Unimplemented),
+    Resumed(Resumed),
+    ApplicationCommandPermissionsUpdate(GuildApplicationCommandPermissions),
+    AutoModerationRuleCreate(AutoModerationRule),
+    AutoModerationRuleUpdate(AutoModerationRule),
+    AutoModerationRuleDelete(AutoModerationRule),
+    AutoModerationActionExecution(AutoModerationActionExecution),
     ChannelCreate(Unimplemented),
     ChannelUpdate(Unimplemented),
     ChannelDelete(Unimplemented),
@@ -190,9 +329,9 @@ pub enum Event {
     ThreadListSync(Unimplemented),
     ThreadMemberUpdate(Unimplemented),
     ThreadMembersUpdate(Unimplemented),
-    EntitlementCreate(Unimplemented),
-    EntitlementUpdate(Unimplemented),
-    EntitlementDelete(Unimplemented),
+    EntitlementCreate(Entitlement),
+    EntitlementUpdate(Entitlement),
+    EntitlementDelete(Entitlement),
     GuildCreate(GuildCreate),
     GuildUpdate(Unimplemented),
     GuildDelete(Unimplemented),
@@ -214,12 +353,12 @@ pub enum Event {
     GuildScheduledEventDelete(Unimplemented),
     GuildScheduledEventUserAdd(Unimplemented),
     GuildScheduledEventUserRemove(Unimplemented),
-    IntegrationCreate(Unimplemented),
-    IntegrationUpdate(Unimplemented),
-    IntegrationDelete(Unimplemented),
+    IntegrationCreate(IntegrationCreate),
+    IntegrationUpdate(IntegrationUpdate),
+    IntegrationDelete(IntegrationDelete),
     InteractionCreate(Unimplemented),
-    InviteCreate(Unimplemented),
-    InviteDelete(Unimplemented),
+    InviteCreate(InviteCreate),
+    InviteDelete(InviteDelete),
     MessageCreate(Unimplemented),
     MessageUpdate(Unimplemented),
     MessageDelete(Unimplemented),
@@ -229,16 +368,16 @@ pub enum Event {
     MessageReactionRemoveAll(Unimplemented),
     MessageReactionRemoveEmoji(Unimplemented),
     PresenceUpdate(PresenceUpdate),
-    StageInstanceCreate(Unimplemented),
-    StageInstanceUpdate(Unimplemented),
-    StageInstanceDelete(Unimplemented),
+    StageInstanceCreate(StageInstance),
+    StageInstanceUpdate(StageInstance),
+    StageInstanceDelete(StageInstance),
     TypingStart(Unimplemented),
-    UserUpdate(Unimplemented),
+    UserUpdate(User),
     VoiceStateUpdate(VoiceState),
-    VoiceServerUpdate(Unimplemented),
-    WebhooksUpdate(Unimplemented),
-    MessagePollVoteAdd(Unimplemented),
-    MessagePollVoteRemove(Unimplemented),
+    VoiceServerUpdate(VoiceServerUpdate),
+    WebhooksUpdate(WebhooksUpdate),
+    MessagePollVoteAdd(MessagePollVote),
+    MessagePollVoteRemove(MessagePollVote),
 }
 
 #[cfg_attr(feature = "clone", derive(Clone))]
@@ -325,6 +464,12 @@ pub struct UnavailableApplication {
     pub flags: ApplicationFlags,
 }
 
+/// Discord docs: https://discord.com/developers/docs/topics/gateway-events#resumed
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct Resumed {}
+
 #[test]
 fn test() {
     use std::assert_matches::assert_matches;
@@ -354,10 +499,9 @@ fn test_dispatch_without_sequence_number() {
         .expect_err("Deserializing should fail because of the missing sequence number");
 
     assert_eq!(".", error.path().to_string());
-    assert_eq!(
-        "No sequence number provided for a `Dispatch` event",
-        error.to_string()
-    );
+    assert!(error
+        .to_string()
+        .starts_with("No sequence number provided for a `Dispatch` event"));
 }
 
 /// Taken from a gateway response on 2024-06-04 with IDs/hashes randomized
@@ -413,6 +557,32 @@ fn test_voice_state_update() {
         .expect("Deserializing should succeed");
 }
 
+/// Taken from a gateway response on 2024-06-14 with IDs/tokens randomized
+#[test]
+fn test_voice_server_update() {
+    let json = r#"{
+        "t": "VOICE_SERVER_UPDATE",
+        "s": 4,
+        "op": 0,
+        "d": {
+            "token": "0123456789abcdef",
+            "guild_id": "2345678901234567890",
+            "endpoint": "c-iad07-1a2b3c4d.discord.media:443"
+        }
+    }"#;
+    let deserializer = &mut serde_json::Deserializer::from_str(json);
+    let event = serde_path_to_error::deserialize::<_, EventPayload>(deserializer)
+        .expect("Deserializing should succeed");
+    let EventPayload::Dispatch(_, Event::VoiceServerUpdate(voice_server_update)) = event else {
+        panic!("Expected a `VOICE_SERVER_UPDATE` dispatch");
+    };
+    assert_eq!("0123456789abcdef", voice_server_update.token);
+    assert_eq!(
+        Some("c-iad07-1a2b3c4d.discord.media:443"),
+        voice_server_update.endpoint.as_deref()
+    );
+}
+
 #[test]
 fn test_path() {
     let json = r#"{
@@ -441,9 +611,9 @@ fn test_path() {
 
     // The actual error message doesn't matter that much,
     // it's just used to confirm the error is with the date field we're expecting.
-    assert_eq!(
-        "the 'year' component could not be parsed",
-        error.to_string()
-    );
+    assert!(error
+        .inner()
+        .to_string()
+        .starts_with("the 'year' component could not be parsed"));
     assert_eq!("d.request_to_speak_timestamp", error.path().to_string());
 }
@@ -1,5 +1,9 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+#[cfg(feature = "serde")]
+use crate::timestamp::unix_millis;
 
 use super::activity::Activity;
 
@@ -8,12 +12,10 @@ use super::activity::Activity;
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub struct PresenceUpdate {
-    /// Unix time (in milliseconds) of when the client went idle, or `None` if the client is not idle.
-    /// Example: `Some(time::macros::datetime!(2024-05-20 20:33:05 EDT).unix_timestamp_nanos() / 1_000)`.
+    /// When the client went idle, or `None` if the client is not idle.
     #[cfg_attr(feature = "serde", serde(default))]
-    // #[serde(with = "time::serde::timestamp::milliseconds::option")]
-    // i128 is broken with this
-    pub since: Option<usize>,
+    #[cfg_attr(feature = "serde", serde(with = "unix_millis::option"))]
+    pub since: Option<OffsetDateTime>,
 
     /// User's activities
     pub activities: Vec<Activity>,
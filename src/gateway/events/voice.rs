@@ -4,7 +4,7 @@ use serde::{Deserialize, Serialize};
 use time::serde::iso8601;
 use time::OffsetDateTime;
 
-use crate::api::objects::guild::GuildMember;
+use crate::api::objects::guild::{GuildId, GuildMember};
 
 /// Discord docs: https://discord.com/developers/docs/resources/voice#voice-state-object
 #[cfg_attr(feature = "clone", derive(Clone))]
@@ -23,6 +23,20 @@ pub struct VoiceState {
     pub self_stream: Option<bool>,
     pub self_video: bool,
     pub suppress: bool,
-    #[cfg_attr(feature = "serde", serde(with = "iso8601"))]
-    pub request_to_speak_timestamp: OffsetDateTime,
+    #[cfg_attr(feature = "serde", serde(default))]
+    #[cfg_attr(feature = "serde", serde(with = "iso8601::option"))]
+    pub request_to_speak_timestamp: Option<OffsetDateTime>,
+}
+
+/// Discord docs: https://discord.com/developers/docs/topics/gateway-events#voice-server-update
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct VoiceServerUpdate {
+    /// Voice connection token.
+    pub token: String,
+    pub guild_id: GuildId,
+    /// Voice server host, or `None` if the allocated voice server is unavailable
+    /// and a new one is being allocated.
+    pub endpoint: Option<String>,
 }
@@ -0,0 +1,13 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::api::objects::{channel::ChannelId, guild::GuildId};
+
+/// Discord docs: https://discord.com/developers/docs/topics/gateway-events#webhooks-update
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct WebhooksUpdate {
+    pub guild_id: GuildId,
+    pub channel_id: ChannelId,
+}
@@ -7,12 +7,10 @@ use futures_util::{SinkExt, StreamExt};
 use tokio::task::JoinSet;
 #[cfg(feature = "gateway")]
 use tokio_tungstenite::{connect_async, tungstenite::Message};
-#[cfg(feature = "gateway")]
-use url::Url;
 
 #[cfg(feature = "gateway")]
 use crate::{
-    api::client::Api,
+    api::client::{Api, Token},
     gateway::events::{
         presence::{PresenceUpdate, Status},
         ConnectionProperties, EventPayload, GatewayIntents, Identify, SequenceNumber,
@@ -26,8 +24,10 @@ pub async fn connect(token: String, intents: GatewayIntents) {
     use events::Hello;
     use serde_json::Value;
 
-    let base_url = Url::parse("https://discord.com/api/").expect("Failed to parse base URL");
-    let api = Api::new(base_url).expect("Failed to build API");
+    let api = Api::builder()
+        .token(Token::Bot(token.clone()))
+        .build()
+        .expect("Failed to build API");
 
     let mut gateway = api.gateway().get_gateway().await.unwrap();
 
@@ -22,6 +22,14 @@ pub mod api;
 #[cfg(all(feature = "private", any(feature = "api", feature = "api_objects")))]
 mod api;
 
+#[cfg(all(not(feature = "private"), feature = "voice"))]
+pub mod voice;
+
+#[cfg(all(feature = "private", feature = "voice"))]
+mod voice;
+
+pub mod timestamp;
+
 /// Calculates the size of an array at compile time.
 ///
 /// Usage:
@@ -49,6 +57,71 @@ macro_rules! array_size {
     };
 }
 
+/// Enum of integer codes that Discord may add to at any time, with an `Unknown` variant
+/// for codes this library doesn't know about yet so they don't fail deserialization.
+/// (De)serializes as the code.
+///
+/// Usage:
+/// ```rust
+/// datrope::codes! {
+///     /// Docs and derives are kept.
+///     #[derive(Debug, Eq, PartialEq)]
+///     pub enum YourCode: u16 {
+///         Foo = 1,
+///         Bar = 2,
+///     }
+/// }
+/// assert_eq!(YourCode::Bar, YourCode::from_code(2));
+/// assert_eq!(YourCode::Unknown(3), YourCode::from_code(3));
+/// assert_eq!(1, YourCode::Foo.code());
+/// ```
+#[macro_export]
+macro_rules! codes {
+    (
+        $(#[$enum_attr:meta])*
+        $vis:vis enum $ident:ident: $repr:ty {
+            $($(#[$attr:meta])* $name:ident = $code:literal,)*
+        }
+    ) => {
+        $(#[$enum_attr])*
+        $vis enum $ident {
+            $($(#[$attr])* $name,)*
+            /// Code this library doesn't know about yet.
+            Unknown($repr),
+        }
+
+        impl $ident {
+            pub fn from_code(code: $repr) -> Self {
+                match code {
+                    $($code => $ident::$name,)*
+                    code => $ident::Unknown(code),
+                }
+            }
+
+            pub fn code(&self) -> $repr {
+                match self {
+                    $($ident::$name => $code,)*
+                    $ident::Unknown(code) => *code,
+                }
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl ::serde::Serialize for $ident {
+            fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                ::serde::Serialize::serialize(&self.code(), serializer)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> ::serde::Deserialize<'de> for $ident {
+            fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                Ok($ident::from_code(<$repr as ::serde::Deserialize>::deserialize(deserializer)?))
+            }
+        }
+    };
+}
+
 /// HashSet for an enum with support for serializing to and deserializing from an integer.
 ///
 /// Usage:
@@ -68,12 +141,56 @@ macro_rules! array_size {
 /// });
 /// let flags = datrope::flags!(your_flags(Foo | Bar));
 /// ```
+///
+/// Flags Discord serializes as strings (such as permissions, which exceed the 53 bits
+/// JavaScript can represent as a number) are serialized as a string instead,
+/// but may be deserialized from either:
+/// ```rust
+/// datrope::flags!(your_flags: i64 as String {
+///     Foo = 1 << 1,
+///     Bar = 1 << 2,
+/// });
+/// let flags = datrope::flags!(your_flags(Foo | Bar));
+/// ```
 #[macro_export]
 macro_rules! flags {
     ( $ident:ident { $($types:ident = $values:expr),+ $(,)? } ) => {
         $crate::flags!($ident:isize { $($types = $values),+ });
     };
     ( $ident:ident:$repr:ty { $($types:ident = $values:expr),+ $(,)? } ) => {
+        $crate::flags!(@define $ident:$repr, [from = "Repr", into = "Repr"], { $($types = $values),+ }, {});
+    };
+    ( $ident:ident:$repr:ty as String { $($types:ident = $values:expr),+ $(,)? } ) => {
+        $crate::flags!(@define $ident:$repr, [try_from = "StringRepr", into = "StringRepr"], { $($types = $values),+ }, {
+            #[cfg(feature = "serde")]
+            #[derive(Deserialize, Serialize)]
+            #[serde(untagged)]
+            enum StringRepr {
+                String(String),
+                Integer(Repr),
+            }
+
+            #[cfg(feature = "serde")]
+            impl From<Flags> for StringRepr {
+                fn from(value: Flags) -> Self {
+                    StringRepr::String(Repr::from(value).to_string())
+                }
+            }
+
+            #[cfg(feature = "serde")]
+            impl TryFrom<StringRepr> for Flags {
+                type Error = ::std::num::ParseIntError;
+
+                fn try_from(value: StringRepr) -> Result<Self, Self::Error> {
+                    Ok(match value {
+                        StringRepr::String(value) => Flags::from(value.parse::<Repr>()?),
+                        StringRepr::Integer(value) => Flags::from(value),
+                    })
+                }
+            }
+        });
+    };
+    ( @define $ident:ident:$repr:ty, [$($serde:tt)*], { $($types:ident = $values:expr),+ }, { $($items:item)* } ) => {
         pub mod $ident {
             type Repr = $repr;
             #[cfg(feature = "serde")]
@@ -107,8 +224,7 @@ macro_rules! flags {
             #[cfg_attr(any(feature = "clone", feature = "serde"), derive(Clone))]
             #[cfg_attr(feature = "debug", derive(Debug))]
             #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
-            #[cfg_attr(feature = "serde", serde(from = "Repr"))]
-            #[cfg_attr(feature = "serde", serde(into = "Repr"))]
+            #[cfg_attr(feature = "serde", serde($($serde)*))]
             pub struct Flags(::std::collections::HashSet<Flag>);
 
             impl Flags {
@@ -135,6 +251,8 @@ macro_rules! flags {
                     Flags::new(flags.into_iter())
                 }
             }
+
+            $($items)*
         }
     };
     ( $ident:ident( $( $types:ident)|* $(|)? ) ) => {
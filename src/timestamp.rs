@@ -0,0 +1,148 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use time::{error::ComponentRange, OffsetDateTime};
+
+/// Unix time in milliseconds, as used by presences and activities.
+///
+/// `time::serde::timestamp::milliseconds` (de)serializes as an `i128`, which isn't supported
+/// by everything we pass these through, so this is (de)serialized as an `i64` instead.
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "clone", derive(Copy, Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct UnixMillis(pub i64);
+
+impl UnixMillis {
+    const NANOSECONDS_PER_MILLISECOND: i128 = 1_000_000;
+    /// First millisecond of 2015, which snowflake timestamps are relative to.
+    const DISCORD_EPOCH: i64 = 1_420_070_400_000;
+
+    pub fn now() -> Self {
+        OffsetDateTime::now_utc().into()
+    }
+
+    /// When the snowflake (eg, the ID of a user or message) was created.
+    /// IDs are sent as strings, so they need to be parsed first.
+    ///
+    /// Discord docs: https://discord.com/developers/docs/reference#snowflakes
+    pub fn from_snowflake(snowflake: u64) -> Self {
+        // 42 bits after the shift, so it always fits.
+        UnixMillis((snowflake >> 22) as i64 + Self::DISCORD_EPOCH)
+    }
+}
+
+/// `OffsetDateTime` can't go past ±999,999 years, which is well within what fits in an `i64`
+/// of milliseconds, so the clamping here never actually happens.
+impl From<OffsetDateTime> for UnixMillis {
+    fn from(value: OffsetDateTime) -> Self {
+        let millis = value.unix_timestamp_nanos() / Self::NANOSECONDS_PER_MILLISECOND;
+        UnixMillis(i64::try_from(millis).unwrap_or(if millis < 0 { i64::MIN } else { i64::MAX }))
+    }
+}
+
+impl TryFrom<UnixMillis> for OffsetDateTime {
+    type Error = ComponentRange;
+
+    fn try_from(value: UnixMillis) -> Result<Self, Self::Error> {
+        OffsetDateTime::from_unix_timestamp_nanos(
+            i128::from(value.0) * UnixMillis::NANOSECONDS_PER_MILLISECOND,
+        )
+    }
+}
+
+/// (De)serializes an `OffsetDateTime` as Unix time in milliseconds.
+///
+/// Usage:
+/// ```rust
+/// use serde::{Deserialize, Serialize};
+/// use time::OffsetDateTime;
+///
+/// #[derive(Deserialize, Serialize)]
+/// struct Foo {
+///     #[serde(with = "datrope::timestamp::unix_millis")]
+///     created_at: OffsetDateTime,
+///     #[serde(default, with = "datrope::timestamp::unix_millis::option")]
+///     since: Option<OffsetDateTime>,
+/// }
+///
+/// let foo: Foo = serde_json::from_str(r#"{"created_at": 1718313600123, "since": null}"#).unwrap();
+/// assert_eq!(1718313600, foo.created_at.unix_timestamp());
+/// assert_eq!(123, foo.created_at.millisecond());
+/// assert_eq!(None, foo.since);
+/// ```
+#[cfg(feature = "serde")]
+pub mod unix_millis {
+    use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+    use time::OffsetDateTime;
+
+    use super::UnixMillis;
+
+    pub fn serialize<S: Serializer>(
+        datetime: &OffsetDateTime,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        UnixMillis::from(*datetime).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<OffsetDateTime, D::Error> {
+        OffsetDateTime::try_from(UnixMillis::deserialize(deserializer)?).map_err(de::Error::custom)
+    }
+
+    pub mod option {
+        use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+        use time::OffsetDateTime;
+
+        use super::UnixMillis;
+
+        pub fn serialize<S: Serializer>(
+            datetime: &Option<OffsetDateTime>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            datetime.map(UnixMillis::from).serialize(serializer)
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Option<OffsetDateTime>, D::Error> {
+            Option::<UnixMillis>::deserialize(deserializer)?
+                .map(OffsetDateTime::try_from)
+                .transpose()
+                .map_err(de::Error::custom)
+        }
+    }
+}
+
+/// (De)serializes an `OffsetDateTime` as RFC 3339, for timestamps sent to Discord,
+/// which rejects the six digit years written by `time::serde::iso8601`.
+#[cfg(feature = "serde")]
+pub mod rfc3339 {
+    pub use time::serde::rfc3339::{deserialize, serialize};
+
+    pub mod option {
+        pub use time::serde::rfc3339::option::{deserialize, serialize};
+    }
+}
+
+#[test]
+fn test_offset_date_time_round_trip() {
+    let datetime = OffsetDateTime::from_unix_timestamp_nanos(1_716_251_585_123_000_000)
+        .expect("timestamp should be in range");
+    let unix_millis = UnixMillis::from(datetime);
+    assert_eq!(UnixMillis(1716251585123), unix_millis);
+    assert_eq!(Ok(datetime), OffsetDateTime::try_from(unix_millis));
+}
+
+/// Example taken from https://discord.com/developers/docs/reference#snowflakes
+#[test]
+fn test_from_snowflake() {
+    assert_eq!(
+        UnixMillis(1462015105796),
+        UnixMillis::from_snowflake(175928847299117063)
+    );
+    assert_eq!(
+        UnixMillis(UnixMillis::DISCORD_EPOCH),
+        UnixMillis::from_snowflake(0)
+    );
+}
@@ -0,0 +1,428 @@
+use serde::{de, ser::SerializeStruct, Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+use crate::{
+    api::objects::{guild::GuildId, user::UserId},
+    flags,
+};
+
+// https://discord.com/developers/docs/topics/opcodes-and-status-codes#voice-voice-opcodes
+#[derive(Deserialize_repr, Serialize_repr)]
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[repr(u8)]
+pub enum Opcode {
+    Identify = 0,
+    SelectProtocol = 1,
+    Ready = 2,
+    Heartbeat = 3,
+    SessionDescription = 4,
+    Speaking = 5,
+    HeartbeatAck = 6,
+    Resume = 7,
+    Hello = 8,
+    Resumed = 9,
+    ClientsConnect = 11,
+    ClientDisconnect = 13,
+}
+
+impl Opcode {
+    fn from_u8(opcode: u8) -> Option<Self> {
+        match opcode {
+            0 => Some(Opcode::Identify),
+            1 => Some(Opcode::SelectProtocol),
+            2 => Some(Opcode::Ready),
+            3 => Some(Opcode::Heartbeat),
+            4 => Some(Opcode::SessionDescription),
+            5 => Some(Opcode::Speaking),
+            6 => Some(Opcode::HeartbeatAck),
+            7 => Some(Opcode::Resume),
+            8 => Some(Opcode::Hello),
+            9 => Some(Opcode::Resumed),
+            11 => Some(Opcode::ClientsConnect),
+            13 => Some(Opcode::ClientDisconnect),
+            _ => None,
+        }
+    }
+}
+
+// https://discord.com/developers/docs/topics/voice-connections#establishing-a-voice-websocket-connection
+// See `gateway::events::EventPayload` for why this isn't an adjacently tagged enum.
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub enum VoicePayload {
+    Identify(Identify),
+    SelectProtocol(SelectProtocol),
+    Ready(Ready),
+    Heartbeat(Heartbeat),
+    SessionDescription(SessionDescription),
+    Speaking(Speaking),
+    HeartbeatAck(HeartbeatAck),
+    Resume(Resume),
+    Hello(Hello),
+    Resumed,
+    ClientsConnect(ClientsConnect),
+    ClientDisconnect(ClientDisconnect),
+    /// Opcodes this library doesn't handle yet, such as the ones used for
+    /// end-to-end encryption (DAVE).
+    Unimplemented(u8, Value),
+}
+
+impl VoicePayload {
+    pub const FIELD_OPCODE: &'static str = "op";
+    pub const FIELD_DATA: &'static str = "d";
+    pub const FIELD_SEQUENCE_NUMBER: &'static str = "seq";
+
+    pub fn opcode(&self) -> u8 {
+        let opcode = match self {
+            VoicePayload::Identify(_) => Opcode::Identify,
+            VoicePayload::SelectProtocol(_) => Opcode::SelectProtocol,
+            VoicePayload::Ready(_) => Opcode::Ready,
+            VoicePayload::Heartbeat(_) => Opcode::Heartbeat,
+            VoicePayload::SessionDescription(_) => Opcode::SessionDescription,
+            VoicePayload::Speaking(_) => Opcode::Speaking,
+            VoicePayload::HeartbeatAck(_) => Opcode::HeartbeatAck,
+            VoicePayload::Resume(_) => Opcode::Resume,
+            VoicePayload::Hello(_) => Opcode::Hello,
+            VoicePayload::Resumed => Opcode::Resumed,
+            VoicePayload::ClientsConnect(_) => Opcode::ClientsConnect,
+            VoicePayload::ClientDisconnect(_) => Opcode::ClientDisconnect,
+            VoicePayload::Unimplemented(opcode, _) => return *opcode,
+        };
+        opcode as u8
+    }
+}
+
+impl Serialize for VoicePayload {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut state = serializer.serialize_struct("VoicePayload", 2)?;
+        state.serialize_field(Self::FIELD_OPCODE, &self.opcode())?;
+
+        match self {
+            VoicePayload::Identify(data) => state.serialize_field(Self::FIELD_DATA, data)?,
+            VoicePayload::SelectProtocol(data) => state.serialize_field(Self::FIELD_DATA, data)?,
+            VoicePayload::Ready(data) => state.serialize_field(Self::FIELD_DATA, data)?,
+            VoicePayload::Heartbeat(data) => state.serialize_field(Self::FIELD_DATA, data)?,
+            VoicePayload::SessionDescription(data) => {
+                state.serialize_field(Self::FIELD_DATA, data)?
+            }
+            VoicePayload::Speaking(data) => state.serialize_field(Self::FIELD_DATA, data)?,
+            VoicePayload::HeartbeatAck(data) => state.serialize_field(Self::FIELD_DATA, data)?,
+            VoicePayload::Resume(data) => state.serialize_field(Self::FIELD_DATA, data)?,
+            VoicePayload::Hello(data) => state.serialize_field(Self::FIELD_DATA, data)?,
+            VoicePayload::Resumed => state.serialize_field(Self::FIELD_DATA, &Value::Null)?,
+            VoicePayload::ClientsConnect(data) => state.serialize_field(Self::FIELD_DATA, data)?,
+            VoicePayload::ClientDisconnect(data) => {
+                state.serialize_field(Self::FIELD_DATA, data)?
+            }
+            VoicePayload::Unimplemented(_, data) => {
+                state.serialize_field(Self::FIELD_DATA, data)?
+            }
+        }
+
+        state.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for VoicePayload {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(SequencedVoicePayload::deserialize(deserializer)?.payload)
+    }
+}
+
+/// A payload received from the voice gateway, along with the sequence number
+/// that needs to be acknowledged in heartbeats and when resuming.
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct SequencedVoicePayload {
+    pub sequence_number: Option<u64>,
+    pub payload: VoicePayload,
+}
+
+impl<'de> Deserialize<'de> for SequencedVoicePayload {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        struct RawVoicePayload {
+            #[serde(rename = "op")]
+            opcode: u8,
+            #[serde(rename = "seq", default)]
+            sequence_number: Option<u64>,
+            #[serde(rename = "d", default)]
+            data: Value,
+        }
+
+        fn data<T: de::DeserializeOwned, E: de::Error>(data: Value) -> Result<T, E> {
+            serde_json::from_value(data).map_err(de::Error::custom)
+        }
+
+        let raw_payload = RawVoicePayload::deserialize(deserializer)?;
+        let payload = match Opcode::from_u8(raw_payload.opcode) {
+            Some(Opcode::Identify) => VoicePayload::Identify(data(raw_payload.data)?),
+            Some(Opcode::SelectProtocol) => VoicePayload::SelectProtocol(data(raw_payload.data)?),
+            Some(Opcode::Ready) => VoicePayload::Ready(data(raw_payload.data)?),
+            Some(Opcode::Heartbeat) => VoicePayload::Heartbeat(data(raw_payload.data)?),
+            Some(Opcode::SessionDescription) => {
+                VoicePayload::SessionDescription(data(raw_payload.data)?)
+            }
+            Some(Opcode::Speaking) => VoicePayload::Speaking(data(raw_payload.data)?),
+            Some(Opcode::HeartbeatAck) => VoicePayload::HeartbeatAck(data(raw_payload.data)?),
+            Some(Opcode::Resume) => VoicePayload::Resume(data(raw_payload.data)?),
+            Some(Opcode::Hello) => VoicePayload::Hello(data(raw_payload.data)?),
+            Some(Opcode::Resumed) => VoicePayload::Resumed,
+            Some(Opcode::ClientsConnect) => VoicePayload::ClientsConnect(data(raw_payload.data)?),
+            Some(Opcode::ClientDisconnect) => {
+                VoicePayload::ClientDisconnect(data(raw_payload.data)?)
+            }
+            None => VoicePayload::Unimplemented(raw_payload.opcode, raw_payload.data),
+        };
+
+        Ok(SequencedVoicePayload {
+            sequence_number: raw_payload.sequence_number,
+            payload,
+        })
+    }
+}
+
+/// Discord docs: https://discord.com/developers/docs/topics/voice-connections#establishing-a-voice-websocket-connection-example-voice-identify-payload
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Deserialize, Serialize)]
+pub struct Identify {
+    pub server_id: GuildId,
+    pub user_id: UserId,
+    pub session_id: String,
+    pub token: String,
+}
+
+/// Discord docs: https://discord.com/developers/docs/topics/voice-connections#establishing-a-voice-udp-connection-example-select-protocol-payload
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Deserialize, Serialize)]
+pub struct SelectProtocol {
+    /// Always `"udp"`.
+    pub protocol: String,
+    pub data: SelectProtocolData,
+}
+
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Deserialize, Serialize)]
+pub struct SelectProtocolData {
+    /// External IP address found through IP discovery.
+    pub address: String,
+    /// External port found through IP discovery.
+    pub port: u16,
+    pub mode: EncryptionMode,
+}
+
+/// Discord docs: https://discord.com/developers/docs/topics/voice-connections#establishing-a-voice-websocket-connection-example-voice-ready-payload
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Deserialize, Serialize)]
+pub struct Ready {
+    pub ssrc: u32,
+    pub ip: String,
+    pub port: u16,
+    pub modes: Vec<EncryptionMode>,
+}
+
+/// Discord docs: https://discord.com/developers/docs/topics/voice-connections#transport-encryption-modes
+#[cfg_attr(feature = "clone", derive(Copy, Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum EncryptionMode {
+    AeadAes256GcmRtpsize,
+    AeadXchacha20Poly1305Rtpsize,
+    /// Deprecated or unknown modes this library doesn't support.
+    #[serde(other)]
+    Unsupported,
+}
+
+/// Discord docs: https://discord.com/developers/docs/topics/voice-connections#heartbeating
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Deserialize, Serialize)]
+pub struct Heartbeat {
+    /// Nonce echoed back in the `HeartbeatAck`.
+    pub t: u64,
+    /// Latest sequence number received from the voice gateway.
+    pub seq_ack: Option<u64>,
+}
+
+/// Discord docs: https://discord.com/developers/docs/topics/voice-connections#heartbeating-example-heartbeat-ack-payload-since-v8
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Deserialize, Serialize)]
+pub struct HeartbeatAck {
+    pub t: u64,
+}
+
+/// Discord docs: https://discord.com/developers/docs/topics/voice-connections#establishing-a-voice-udp-connection-example-session-description-payload
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Deserialize, Serialize)]
+pub struct SessionDescription {
+    pub mode: EncryptionMode,
+    pub secret_key: Vec<u8>,
+}
+
+/// Discord docs: https://discord.com/developers/docs/topics/voice-connections#speaking
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Deserialize, Serialize)]
+pub struct Speaking {
+    pub speaking: SpeakingFlags,
+    /// Always `0` for bots.
+    #[serde(default)]
+    pub delay: u32,
+    pub ssrc: u32,
+    /// Only set when received, for the user that started or stopped speaking.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<UserId>,
+}
+
+flags!(speaking_flags: u8 {
+    Microphone = 1 << 0,
+    Soundshare = 1 << 1,
+    Priority = 1 << 2,
+});
+pub use speaking_flags::Flags as SpeakingFlags;
+
+/// Discord docs: https://discord.com/developers/docs/topics/voice-connections#resuming-voice-connection-example-resume-connection-payload-since-v8
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Deserialize, Serialize)]
+pub struct Resume {
+    pub server_id: GuildId,
+    pub session_id: String,
+    pub token: String,
+    pub seq_ack: Option<u64>,
+}
+
+/// Discord docs: https://discord.com/developers/docs/topics/voice-connections#heartbeating-example-hello-payload
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Deserialize, Serialize)]
+pub struct Hello {
+    /// In milliseconds.
+    pub heartbeat_interval: f64,
+}
+
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Deserialize, Serialize)]
+pub struct ClientsConnect {
+    pub user_ids: Vec<UserId>,
+}
+
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Deserialize, Serialize)]
+pub struct ClientDisconnect {
+    pub user_id: UserId,
+}
+
+/// Discord docs: https://discord.com/developers/docs/topics/opcodes-and-status-codes#voice-voice-close-event-codes
+#[cfg_attr(feature = "clone", derive(Copy, Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Eq, PartialEq)]
+pub enum VoiceCloseCode {
+    UnknownOpcode = 4001,
+    FailedToDecodePayload = 4002,
+    NotAuthenticated = 4003,
+    AuthenticationFailed = 4004,
+    AlreadyAuthenticated = 4005,
+    SessionNoLongerValid = 4006,
+    SessionTimeout = 4009,
+    ServerNotFound = 4011,
+    UnknownProtocol = 4012,
+    Disconnected = 4014,
+    VoiceServerCrashed = 4015,
+    UnknownEncryptionMode = 4016,
+    BadRequest = 4020,
+    RateLimited = 4021,
+    CallTerminated = 4022,
+}
+
+impl VoiceCloseCode {
+    pub fn from_u16(code: u16) -> Option<Self> {
+        match code {
+            4001 => Some(VoiceCloseCode::UnknownOpcode),
+            4002 => Some(VoiceCloseCode::FailedToDecodePayload),
+            4003 => Some(VoiceCloseCode::NotAuthenticated),
+            4004 => Some(VoiceCloseCode::AuthenticationFailed),
+            4005 => Some(VoiceCloseCode::AlreadyAuthenticated),
+            4006 => Some(VoiceCloseCode::SessionNoLongerValid),
+            4009 => Some(VoiceCloseCode::SessionTimeout),
+            4011 => Some(VoiceCloseCode::ServerNotFound),
+            4012 => Some(VoiceCloseCode::UnknownProtocol),
+            4014 => Some(VoiceCloseCode::Disconnected),
+            4015 => Some(VoiceCloseCode::VoiceServerCrashed),
+            4016 => Some(VoiceCloseCode::UnknownEncryptionMode),
+            4020 => Some(VoiceCloseCode::BadRequest),
+            4021 => Some(VoiceCloseCode::RateLimited),
+            4022 => Some(VoiceCloseCode::CallTerminated),
+            _ => None,
+        }
+    }
+
+    /// Whether the session can be resumed after the connection is closed with this code.
+    /// Codes outside of this enum (eg, `1001` when Discord restarts a server) are resumable too.
+    pub fn is_resumable(&self) -> bool {
+        !matches!(
+            self,
+            VoiceCloseCode::AuthenticationFailed
+                | VoiceCloseCode::SessionNoLongerValid
+                | VoiceCloseCode::SessionTimeout
+                | VoiceCloseCode::ServerNotFound
+                | VoiceCloseCode::UnknownProtocol
+                | VoiceCloseCode::Disconnected
+                | VoiceCloseCode::UnknownEncryptionMode
+                | VoiceCloseCode::RateLimited
+                | VoiceCloseCode::CallTerminated
+        )
+    }
+}
+
+/// JSON taken from https://discord.com/developers/docs/topics/voice-connections#establishing-a-voice-websocket-connection-example-voice-ready-payload
+#[test]
+fn test_ready() {
+    use std::assert_matches::assert_matches;
+
+    let json = r#"{
+        "op": 2,
+        "d": {
+            "ssrc": 1,
+            "ip": "127.0.0.1",
+            "port": 1234,
+            "modes": ["aead_xchacha20_poly1305_rtpsize", "aead_aes256_gcm_rtpsize", "xsalsa20_poly1305"],
+            "heartbeat_interval": 1
+        }
+    }"#;
+    let payload: SequencedVoicePayload =
+        serde_json::from_str(json).expect("Deserializing should succeed");
+    assert_eq!(None, payload.sequence_number);
+    let VoicePayload::Ready(ready) = payload.payload else {
+        panic!("Expected a `Ready` payload");
+    };
+    assert_eq!(1, ready.ssrc);
+    assert_eq!(
+        vec![
+            EncryptionMode::AeadXchacha20Poly1305Rtpsize,
+            EncryptionMode::AeadAes256GcmRtpsize,
+            EncryptionMode::Unsupported
+        ],
+        ready.modes
+    );
+
+    let json = r#"{"op": 21, "seq": 10, "d": {"protocol_version": 1}}"#;
+    let payload: SequencedVoicePayload =
+        serde_json::from_str(json).expect("Deserializing should succeed");
+    assert_eq!(Some(10), payload.sequence_number);
+    assert_matches!(payload.payload, VoicePayload::Unimplemented(21, _));
+}
@@ -0,0 +1,453 @@
+use std::{collections::VecDeque, time::Duration};
+
+use futures_util::{
+    stream::{SplitSink, SplitStream},
+    SinkExt, StreamExt,
+};
+use tokio::{
+    net::TcpStream,
+    sync::mpsc::{self, UnboundedReceiver, UnboundedSender},
+    time::{interval, Interval},
+};
+use tokio_tungstenite::{connect_async, tungstenite::Message, MaybeTlsStream, WebSocketStream};
+
+use crate::{
+    api::objects::{guild::GuildId, user::UserId},
+    timestamp::UnixMillis,
+};
+
+use super::{
+    events::{
+        EncryptionMode, Heartbeat, Identify, Ready, Resume, SelectProtocol, SelectProtocolData,
+        SequencedVoicePayload, SessionDescription, Speaking, SpeakingFlags, VoiceCloseCode,
+        VoicePayload,
+    },
+    Result, VoiceConnectionInfo, VoiceError,
+};
+
+type WebSocket = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub enum VoiceEvent {
+    /// Payloads not handled by the connection itself (heartbeats and hellos are).
+    /// `VoicePayload::Resumed` is received each time a dropped connection is resumed.
+    Payload(VoicePayload),
+    /// The connection was closed (with the close code, if any) and couldn't be resumed.
+    /// No more events will be received after this.
+    Closed(Option<u16>),
+}
+
+/// Connection to a voice gateway.
+/// Heartbeats are sent and dropped connections are resumed in the background
+/// for as long as this is held.
+///
+/// Discord docs: https://discord.com/developers/docs/topics/voice-connections
+pub struct VoiceGateway {
+    ready: Ready,
+    commands: UnboundedSender<VoicePayload>,
+    events: UnboundedReceiver<VoiceEvent>,
+    pending_events: VecDeque<VoiceEvent>,
+}
+
+impl VoiceGateway {
+    /// Connects to the voice gateway and identifies, waiting for it to be ready.
+    pub async fn connect(info: VoiceConnectionInfo) -> Result<VoiceGateway> {
+        let (websocket, _response) = connect_async(info.url()?).await?;
+        let (mut sink, mut stream) = websocket.split();
+
+        send(
+            &mut sink,
+            &VoicePayload::Identify(Identify {
+                server_id: GuildId(info.server_id.0.clone()),
+                user_id: UserId(info.user_id.0.clone()),
+                session_id: info.session_id.clone(),
+                token: info.token.clone(),
+            }),
+        )
+        .await?;
+
+        let mut sequence_number = None;
+        let mut heartbeat_interval = None;
+        let mut ready = None;
+        let mut pending_events = VecDeque::new();
+        while heartbeat_interval.is_none() || ready.is_none() {
+            let payload = receive(&mut stream).await?;
+            sequence_number = payload.sequence_number.or(sequence_number);
+            match payload.payload {
+                VoicePayload::Hello(hello) => {
+                    heartbeat_interval =
+                        Some(Duration::from_secs_f64(hello.heartbeat_interval / 1_000.0))
+                }
+                VoicePayload::Ready(payload) => ready = Some(payload),
+                VoicePayload::HeartbeatAck(_) => {}
+                payload => pending_events.push_back(VoiceEvent::Payload(payload)),
+            }
+        }
+
+        let (commands_sender, commands_receiver) = mpsc::unbounded_channel();
+        let (events_sender, events_receiver) = mpsc::unbounded_channel();
+        let connection = Connection {
+            info,
+            sink,
+            stream,
+            heartbeat: interval(heartbeat_interval.expect("Checked by the loop condition")),
+            sequence_number,
+            commands: commands_receiver,
+            events: events_sender,
+        };
+        tokio::spawn(connection.run());
+
+        Ok(VoiceGateway {
+            ready: ready.expect("Checked by the loop condition"),
+            commands: commands_sender,
+            events: events_receiver,
+            pending_events,
+        })
+    }
+
+    /// SSRC, UDP address, and encryption modes of the voice server.
+    pub fn ready(&self) -> &Ready {
+        &self.ready
+    }
+
+    /// Selects the UDP protocol with the external address and port found through IP discovery,
+    /// and waits for the session description with the secret key to encrypt voice data with.
+    ///
+    /// Discord docs: https://discord.com/developers/docs/topics/voice-connections#establishing-a-voice-udp-connection
+    pub async fn select_protocol(
+        &mut self,
+        address: String,
+        port: u16,
+        mode: EncryptionMode,
+    ) -> Result<SessionDescription> {
+        self.send(VoicePayload::SelectProtocol(SelectProtocol {
+            protocol: String::from("udp"),
+            data: SelectProtocolData {
+                address,
+                port,
+                mode,
+            },
+        }))?;
+
+        loop {
+            match self.events.recv().await {
+                Some(VoiceEvent::Payload(VoicePayload::SessionDescription(
+                    session_description,
+                ))) => return Ok(session_description),
+                Some(VoiceEvent::Closed(code)) => return Err(VoiceError::Closed(code)),
+                Some(event) => self.pending_events.push_back(event),
+                None => return Err(VoiceError::Disconnected),
+            }
+        }
+    }
+
+    /// Must be sent at least once before sending voice data.
+    ///
+    /// Discord docs: https://discord.com/developers/docs/topics/voice-connections#speaking
+    pub fn speaking(&self, speaking: SpeakingFlags) -> Result<()> {
+        self.send(VoicePayload::Speaking(Speaking {
+            speaking,
+            delay: 0,
+            ssrc: self.ready.ssrc,
+            user_id: None,
+        }))
+    }
+
+    pub fn send(&self, payload: VoicePayload) -> Result<()> {
+        self.commands
+            .send(payload)
+            .map_err(|_| VoiceError::Disconnected)
+    }
+
+    /// Waits for the next event, or returns `None` once the connection has closed
+    /// and all events have been received.
+    pub async fn next_event(&mut self) -> Option<VoiceEvent> {
+        match self.pending_events.pop_front() {
+            Some(event) => Some(event),
+            None => self.events.recv().await,
+        }
+    }
+}
+
+struct Connection {
+    info: VoiceConnectionInfo,
+    sink: SplitSink<WebSocket, Message>,
+    stream: SplitStream<WebSocket>,
+    heartbeat: Interval,
+    sequence_number: Option<u64>,
+    commands: UnboundedReceiver<VoicePayload>,
+    events: UnboundedSender<VoiceEvent>,
+}
+
+impl Connection {
+    async fn run(mut self) {
+        let code = loop {
+            let error = match self.handle_payloads().await {
+                Ok(()) => return,
+                Err(error) => error,
+            };
+            let resumable = match &error {
+                VoiceError::WebSocketError(_) | VoiceError::Closed(None) => true,
+                VoiceError::Closed(Some(code)) => {
+                    VoiceCloseCode::from_u16(*code).is_none_or(|code| code.is_resumable())
+                }
+                _ => false,
+            };
+            if !resumable {
+                break error;
+            }
+            if let Err(error) = self.resume().await {
+                break error;
+            }
+        };
+
+        let code = match code {
+            VoiceError::Closed(code) => code,
+            _ => None,
+        };
+        // The receiver being dropped just means nobody is listening anymore.
+        let _ = self.events.send(VoiceEvent::Closed(code));
+    }
+
+    /// Handles payloads until the websocket closes, or until the `VoiceGateway` is dropped.
+    async fn handle_payloads(&mut self) -> Result<()> {
+        loop {
+            tokio::select! {
+                _ = self.heartbeat.tick() => {
+                    send(&mut self.sink, &VoicePayload::Heartbeat(Heartbeat {
+                        t: UnixMillis::now().0 as u64,
+                        seq_ack: self.sequence_number,
+                    }))
+                    .await?;
+                }
+                command = self.commands.recv() => match command {
+                    Some(payload) => send(&mut self.sink, &payload).await?,
+                    None => {
+                        self.sink.close().await?;
+                        return Ok(());
+                    }
+                },
+                payload = receive(&mut self.stream) => self.handle_payload(payload?),
+            }
+        }
+    }
+
+    fn handle_payload(&mut self, payload: SequencedVoicePayload) {
+        self.sequence_number = payload.sequence_number.or(self.sequence_number);
+        match payload.payload {
+            VoicePayload::Hello(hello) => {
+                self.heartbeat =
+                    interval(Duration::from_secs_f64(hello.heartbeat_interval / 1_000.0))
+            }
+            VoicePayload::HeartbeatAck(_) => {}
+            payload => {
+                let _ = self.events.send(VoiceEvent::Payload(payload));
+            }
+        }
+    }
+
+    /// Discord docs: https://discord.com/developers/docs/topics/voice-connections#resuming-voice-connection
+    async fn resume(&mut self) -> Result<()> {
+        let (websocket, _response) = connect_async(self.info.url()?).await?;
+        (self.sink, self.stream) = websocket.split();
+
+        send(
+            &mut self.sink,
+            &VoicePayload::Resume(Resume {
+                server_id: GuildId(self.info.server_id.0.clone()),
+                session_id: self.info.session_id.clone(),
+                token: self.info.token.clone(),
+                seq_ack: self.sequence_number,
+            }),
+        )
+        .await?;
+
+        loop {
+            let payload = receive(&mut self.stream).await?;
+            let resumed = matches!(payload.payload, VoicePayload::Resumed);
+            self.handle_payload(payload);
+            if resumed {
+                return Ok(());
+            }
+        }
+    }
+}
+
+async fn send(sink: &mut SplitSink<WebSocket, Message>, payload: &VoicePayload) -> Result<()> {
+    let message = Message::Text(serde_json::to_string(payload)?);
+    Ok(sink.send(message).await?)
+}
+
+async fn receive(stream: &mut SplitStream<WebSocket>) -> Result<SequencedVoicePayload> {
+    loop {
+        match stream.next().await {
+            Some(Ok(Message::Text(message))) => return Ok(serde_json::from_str(&message)?),
+            Some(Ok(Message::Close(frame))) => {
+                return Err(VoiceError::Closed(frame.map(|frame| frame.code.into())))
+            }
+            // Binary messages are only used for end-to-end encryption (DAVE), which isn't supported.
+            Some(Ok(_)) => {}
+            Some(Err(error)) => return Err(error.into()),
+            None => return Err(VoiceError::Closed(None)),
+        }
+    }
+}
+
+/// Runs through a connection against a stand-in voice server on loopback.
+#[tokio::test]
+async fn test_connect_and_resume() {
+    use std::assert_matches::assert_matches;
+
+    use serde_json::{json, Value};
+    use tokio::net::TcpListener;
+    use tokio_tungstenite::{
+        accept_async,
+        tungstenite::protocol::{frame::coding::CloseCode, CloseFrame},
+    };
+
+    use super::events::speaking_flags;
+    use crate::flags;
+
+    async fn receive_json(websocket: &mut WebSocketStream<TcpStream>, opcode: u64) -> Value {
+        loop {
+            let Some(Ok(Message::Text(message))) = websocket.next().await else {
+                panic!("Expected a text message");
+            };
+            let payload: Value = serde_json::from_str(&message).unwrap();
+            if payload["op"] == opcode {
+                return payload["d"].clone();
+            }
+        }
+    }
+
+    async fn send_json(websocket: &mut WebSocketStream<TcpStream>, payload: Value) {
+        websocket
+            .send(Message::Text(payload.to_string()))
+            .await
+            .unwrap();
+    }
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    let server = tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut websocket = accept_async(stream).await.unwrap();
+        send_json(
+            &mut websocket,
+            json!({"op": 8, "d": {"heartbeat_interval": 10_000.0}}),
+        )
+        .await;
+        let identify = receive_json(&mut websocket, 0).await;
+        assert_eq!("41771983423143937", identify["server_id"]);
+        assert_eq!("my_token", identify["token"]);
+        send_json(
+            &mut websocket,
+            json!({"op": 2, "seq": 1, "d": {
+                "ssrc": 1,
+                "ip": "127.0.0.1",
+                "port": 1234,
+                "modes": ["aead_aes256_gcm_rtpsize"]
+            }}),
+        )
+        .await;
+
+        let select_protocol = receive_json(&mut websocket, 1).await;
+        assert_eq!("aead_aes256_gcm_rtpsize", select_protocol["data"]["mode"]);
+        send_json(
+            &mut websocket,
+            json!({"op": 4, "seq": 2, "d": {"mode": "aead_aes256_gcm_rtpsize", "secret_key": vec![7; 32]}}),
+        )
+        .await;
+
+        let speaking = receive_json(&mut websocket, 5).await;
+        assert_eq!(json!({"speaking": 1, "delay": 0, "ssrc": 1}), speaking);
+
+        websocket
+            .close(Some(CloseFrame {
+                code: CloseCode::from(VoiceCloseCode::VoiceServerCrashed as u16),
+                reason: "".into(),
+            }))
+            .await
+            .unwrap();
+
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut websocket = accept_async(stream).await.unwrap();
+        let resume = receive_json(&mut websocket, 7).await;
+        assert_eq!("my_session", resume["session_id"]);
+        assert_eq!(2, resume["seq_ack"]);
+        send_json(
+            &mut websocket,
+            json!({"op": 8, "d": {"heartbeat_interval": 10_000.0}}),
+        )
+        .await;
+        send_json(&mut websocket, json!({"op": 9, "d": null})).await;
+        let heartbeat = receive_json(&mut websocket, 3).await;
+        assert_eq!(2, heartbeat["seq_ack"]);
+        send_json(&mut websocket, json!({"op": 6, "d": {"t": heartbeat["t"]}})).await;
+
+        // Not a voice close code, but still resumable.
+        websocket
+            .close(Some(CloseFrame {
+                code: CloseCode::Away,
+                reason: "".into(),
+            }))
+            .await
+            .unwrap();
+
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut websocket = accept_async(stream).await.unwrap();
+        let resume = receive_json(&mut websocket, 7).await;
+        assert_eq!("my_session", resume["session_id"]);
+        send_json(&mut websocket, json!({"op": 9, "d": null})).await;
+
+        websocket
+            .close(Some(CloseFrame {
+                code: CloseCode::from(VoiceCloseCode::Disconnected as u16),
+                reason: "".into(),
+            }))
+            .await
+            .unwrap();
+    });
+
+    let mut gateway = VoiceGateway::connect(VoiceConnectionInfo {
+        server_id: GuildId(String::from("41771983423143937")),
+        user_id: UserId(String::from("104694319306248192")),
+        session_id: String::from("my_session"),
+        token: String::from("my_token"),
+        endpoint: format!("ws://{address}"),
+    })
+    .await
+    .expect("Connecting should succeed");
+    assert_eq!(1, gateway.ready().ssrc);
+
+    let session_description = gateway
+        .select_protocol(
+            String::from("127.0.0.1"),
+            4321,
+            EncryptionMode::AeadAes256GcmRtpsize,
+        )
+        .await
+        .expect("Selecting the protocol should succeed");
+    assert_eq!(vec![7; 32], session_description.secret_key);
+
+    gateway
+        .speaking(flags!(speaking_flags(Microphone)))
+        .expect("Sending speaking should succeed");
+
+    assert_matches!(
+        gateway.next_event().await,
+        Some(VoiceEvent::Payload(VoicePayload::Resumed))
+    );
+    assert_matches!(
+        gateway.next_event().await,
+        Some(VoiceEvent::Payload(VoicePayload::Resumed))
+    );
+    assert_matches!(
+        gateway.next_event().await,
+        Some(VoiceEvent::Closed(Some(4014)))
+    );
+    assert_matches!(gateway.next_event().await, None);
+
+    server.await.unwrap();
+}
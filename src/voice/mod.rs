@@ -0,0 +1,108 @@
+use thiserror::Error;
+use tokio_tungstenite::tungstenite;
+use url::Url;
+
+use crate::{
+    api::objects::{guild::GuildId, user::UserId},
+    gateway::events::voice::{VoiceServerUpdate, VoiceState},
+};
+
+use self::events::VoiceCloseCode;
+
+pub mod events;
+mod gateway;
+mod udp;
+
+pub use gateway::{VoiceEvent, VoiceGateway};
+pub use udp::{VoicePacket, VoiceUdp};
+
+pub type Result<T> = std::result::Result<T, VoiceError>;
+
+#[derive(Error, Debug)]
+pub enum VoiceError {
+    #[error("The voice server is unavailable, wait for another `VOICE_SERVER_UPDATE`")]
+    MissingEndpoint,
+    #[error("Failed to parse a URL")]
+    UrlParseError(#[from] url::ParseError),
+    #[error("Error from the voice websocket")]
+    WebSocketError(Box<tungstenite::Error>),
+    #[error("Failed to (de)serialize a voice payload")]
+    JsonError(#[from] serde_json::Error),
+    #[error("The voice websocket closed with code {0:?}")]
+    Closed(Option<u16>),
+    #[error("The voice connection is no longer running")]
+    Disconnected,
+    #[error("Error from the voice UDP socket")]
+    IoError(#[from] std::io::Error),
+    #[error("Received an invalid IP discovery response")]
+    InvalidIpDiscovery,
+    #[error("The session's encryption mode isn't supported")]
+    UnsupportedEncryptionMode,
+    #[error("The secret key from the session description is the wrong length")]
+    InvalidSecretKey,
+    #[error("Voice data can't be sent or received until a session description is set")]
+    MissingSessionDescription,
+    #[error("Received a voice packet that couldn't be parsed")]
+    InvalidPacket,
+    #[error("Failed to encrypt or decrypt a voice packet")]
+    EncryptionError,
+}
+
+// Boxed as `tungstenite::Error` is much larger than the other variants.
+impl From<tungstenite::Error> for VoiceError {
+    fn from(value: tungstenite::Error) -> Self {
+        VoiceError::WebSocketError(Box::new(value))
+    }
+}
+
+impl VoiceError {
+    /// The voice close code, if the voice gateway closed the connection with one.
+    pub fn close_code(&self) -> Option<VoiceCloseCode> {
+        match self {
+            VoiceError::Closed(Some(code)) => VoiceCloseCode::from_u16(*code),
+            _ => None,
+        }
+    }
+}
+
+/// Everything needed to connect to a voice server, gathered from the
+/// `VOICE_STATE_UPDATE` and `VOICE_SERVER_UPDATE` events received after sending
+/// a voice state update to the main gateway.
+///
+/// Discord docs: https://discord.com/developers/docs/topics/voice-connections#retrieving-voice-server-information
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct VoiceConnectionInfo {
+    pub server_id: GuildId,
+    pub user_id: UserId,
+    pub session_id: String,
+    pub token: String,
+    /// Host of the voice server, optionally with a port and `ws://` or `wss://` scheme.
+    pub endpoint: String,
+}
+
+impl VoiceConnectionInfo {
+    pub const VERSION: u8 = 8;
+
+    pub fn new(voice_state: VoiceState, voice_server_update: VoiceServerUpdate) -> Result<Self> {
+        Ok(VoiceConnectionInfo {
+            server_id: voice_server_update.guild_id,
+            user_id: UserId(voice_state.user_id),
+            session_id: voice_state.session_id,
+            token: voice_server_update.token,
+            endpoint: voice_server_update
+                .endpoint
+                .ok_or(VoiceError::MissingEndpoint)?,
+        })
+    }
+
+    pub fn url(&self) -> Result<Url> {
+        let mut url = if self.endpoint.starts_with("ws://") || self.endpoint.starts_with("wss://") {
+            Url::parse(&self.endpoint)?
+        } else {
+            Url::parse(&format!("wss://{}", self.endpoint))?
+        };
+        url.set_query(Some(&format!("v={}", Self::VERSION)));
+        Ok(url)
+    }
+}
@@ -0,0 +1,430 @@
+use std::{
+    io,
+    net::{Ipv4Addr, Ipv6Addr, SocketAddr},
+};
+
+use aes_gcm::{
+    aead::{Aead, KeyInit, Payload},
+    Aes256Gcm, Nonce,
+};
+use chacha20poly1305::{XChaCha20Poly1305, XNonce};
+use tokio::net::{lookup_host, UdpSocket};
+
+use super::{
+    events::{EncryptionMode, Ready, SessionDescription},
+    Result, VoiceError,
+};
+
+/// Version 2 with no padding, extension, or CSRCs.
+const RTP_VERSION: u8 = 0x80;
+/// Dynamic payload type Discord uses for Opus.
+const RTP_PAYLOAD_TYPE: u8 = 0x78;
+const RTP_HEADER_LENGTH: usize = 12;
+/// Length of the authentication tag both supported AEAD modes append to the ciphertext.
+const TAG_LENGTH: usize = 16;
+/// Length of the incrementing nonce appended to each encrypted packet.
+const NONCE_LENGTH: usize = 4;
+/// Large enough for any packet sent over an unfragmented UDP datagram.
+const MAX_PACKET_LENGTH: usize = 1460;
+
+const IP_DISCOVERY_REQUEST: u16 = 0x1;
+const IP_DISCOVERY_RESPONSE: u16 = 0x2;
+/// Length of the IP discovery packet, excluding the type and length.
+const IP_DISCOVERY_LENGTH: u16 = 70;
+const IP_DISCOVERY_ADDRESS_LENGTH: usize = 64;
+
+enum Cipher {
+    Aes256Gcm(Box<Aes256Gcm>),
+    XChaCha20Poly1305(Box<XChaCha20Poly1305>),
+}
+
+impl Cipher {
+    fn new(session_description: &SessionDescription) -> Result<Self> {
+        let key = session_description.secret_key.as_slice();
+        match session_description.mode {
+            EncryptionMode::AeadAes256GcmRtpsize => Ok(Cipher::Aes256Gcm(Box::new(
+                Aes256Gcm::new_from_slice(key).map_err(|_| VoiceError::InvalidSecretKey)?,
+            ))),
+            EncryptionMode::AeadXchacha20Poly1305Rtpsize => {
+                Ok(Cipher::XChaCha20Poly1305(Box::new(
+                    XChaCha20Poly1305::new_from_slice(key)
+                        .map_err(|_| VoiceError::InvalidSecretKey)?,
+                )))
+            }
+            EncryptionMode::Unsupported => Err(VoiceError::UnsupportedEncryptionMode),
+        }
+    }
+
+    /// The 4 byte nonce is padded with zeroes to the length each mode requires.
+    fn encrypt(&self, nonce: [u8; NONCE_LENGTH], payload: Payload) -> Result<Vec<u8>> {
+        match self {
+            Cipher::Aes256Gcm(cipher) => {
+                let mut padded = [0; 12];
+                padded[..NONCE_LENGTH].copy_from_slice(&nonce);
+                cipher.encrypt(Nonce::from_slice(&padded), payload)
+            }
+            Cipher::XChaCha20Poly1305(cipher) => {
+                let mut padded = [0; 24];
+                padded[..NONCE_LENGTH].copy_from_slice(&nonce);
+                cipher.encrypt(XNonce::from_slice(&padded), payload)
+            }
+        }
+        .map_err(|_| VoiceError::EncryptionError)
+    }
+
+    fn decrypt(&self, nonce: [u8; NONCE_LENGTH], payload: Payload) -> Result<Vec<u8>> {
+        match self {
+            Cipher::Aes256Gcm(cipher) => {
+                let mut padded = [0; 12];
+                padded[..NONCE_LENGTH].copy_from_slice(&nonce);
+                cipher.decrypt(Nonce::from_slice(&padded), payload)
+            }
+            Cipher::XChaCha20Poly1305(cipher) => {
+                let mut padded = [0; 24];
+                padded[..NONCE_LENGTH].copy_from_slice(&nonce);
+                cipher.decrypt(XNonce::from_slice(&padded), payload)
+            }
+        }
+        .map_err(|_| VoiceError::EncryptionError)
+    }
+}
+
+/// Decrypted Opus frame received from another user in the voice channel.
+/// The SSRC can be mapped to a user with the `Speaking` payloads from the voice gateway.
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct VoicePacket {
+    pub ssrc: u32,
+    pub sequence: u16,
+    pub timestamp: u32,
+    pub opus: Vec<u8>,
+}
+
+/// UDP connection voice data is sent and received over.
+///
+/// After connecting, discover the external address and port with `discover_ip()`,
+/// pass them to `VoiceGateway::select_protocol()`, and set the returned session description
+/// with `set_session_description()` before sending or receiving voice data.
+///
+/// Discord docs: https://discord.com/developers/docs/topics/voice-connections#establishing-a-voice-udp-connection
+pub struct VoiceUdp {
+    socket: UdpSocket,
+    ssrc: u32,
+    sequence: u16,
+    timestamp: u32,
+    nonce: u32,
+    cipher: Option<Cipher>,
+}
+
+impl VoiceUdp {
+    /// Samples per channel in each 20ms Opus frame at 48kHz,
+    /// which the RTP timestamp is incremented by for each frame sent.
+    pub const FRAME_SAMPLES: u32 = 960;
+
+    /// Opus frame of silence. Five of these should be sent when done sending voice data
+    /// to avoid interpolation with the next frames sent.
+    ///
+    /// Discord docs: https://discord.com/developers/docs/topics/voice-connections#voice-data-interpolation
+    pub const SILENCE_FRAME: [u8; 3] = [0xf8, 0xff, 0xfe];
+
+    /// Connects to the voice server's UDP address from the `Ready` payload.
+    pub async fn connect(ready: &Ready) -> Result<Self> {
+        let address = lookup_host((ready.ip.as_str(), ready.port))
+            .await?
+            .next()
+            .ok_or_else(|| io::Error::from(io::ErrorKind::AddrNotAvailable))?;
+        let local_address = match address {
+            SocketAddr::V4(_) => SocketAddr::from((Ipv4Addr::UNSPECIFIED, 0)),
+            SocketAddr::V6(_) => SocketAddr::from((Ipv6Addr::UNSPECIFIED, 0)),
+        };
+        let socket = UdpSocket::bind(local_address).await?;
+        socket.connect(address).await?;
+
+        Ok(Self::from_socket(socket, ready.ssrc))
+    }
+
+    fn from_socket(socket: UdpSocket, ssrc: u32) -> Self {
+        VoiceUdp {
+            socket,
+            ssrc,
+            sequence: 0,
+            timestamp: 0,
+            nonce: 0,
+            cipher: None,
+        }
+    }
+
+    pub fn ssrc(&self) -> u32 {
+        self.ssrc
+    }
+
+    /// Discovers the external address and port of this connection,
+    /// to be passed to `VoiceGateway::select_protocol()`.
+    ///
+    /// Discord docs: https://discord.com/developers/docs/topics/voice-connections#ip-discovery
+    pub async fn discover_ip(&self) -> Result<(String, u16)> {
+        let mut request = Vec::with_capacity(usize::from(IP_DISCOVERY_LENGTH) + 4);
+        request.extend_from_slice(&IP_DISCOVERY_REQUEST.to_be_bytes());
+        request.extend_from_slice(&IP_DISCOVERY_LENGTH.to_be_bytes());
+        request.extend_from_slice(&self.ssrc.to_be_bytes());
+        request.extend_from_slice(&[0; IP_DISCOVERY_ADDRESS_LENGTH]);
+        request.extend_from_slice(&0u16.to_be_bytes());
+        self.socket.send(&request).await?;
+
+        let mut response = [0; MAX_PACKET_LENGTH];
+        let length = self.socket.recv(&mut response).await?;
+        if length != request.len()
+            || response[0..2] != IP_DISCOVERY_RESPONSE.to_be_bytes()
+            || response[2..4] != IP_DISCOVERY_LENGTH.to_be_bytes()
+        {
+            return Err(VoiceError::InvalidIpDiscovery);
+        }
+
+        let address = &response[8..8 + IP_DISCOVERY_ADDRESS_LENGTH];
+        let address = &address[..address
+            .iter()
+            .position(|byte| *byte == 0)
+            .unwrap_or(address.len())];
+        let address =
+            String::from_utf8(address.to_vec()).map_err(|_| VoiceError::InvalidIpDiscovery)?;
+        let port = u16::from_be_bytes([response[72], response[73]]);
+
+        Ok((address, port))
+    }
+
+    /// Sets the encryption mode and secret key returned by `VoiceGateway::select_protocol()`.
+    pub fn set_session_description(
+        &mut self,
+        session_description: &SessionDescription,
+    ) -> Result<()> {
+        self.cipher = Some(Cipher::new(session_description)?);
+        Ok(())
+    }
+
+    /// Encrypts and sends a 20ms Opus frame.
+    /// `VoiceGateway::speaking()` must be called before the first frame is sent.
+    pub async fn send_opus(&mut self, opus: &[u8]) -> Result<()> {
+        let cipher = self
+            .cipher
+            .as_ref()
+            .ok_or(VoiceError::MissingSessionDescription)?;
+
+        let mut header = [0; RTP_HEADER_LENGTH];
+        header[0] = RTP_VERSION;
+        header[1] = RTP_PAYLOAD_TYPE;
+        header[2..4].copy_from_slice(&self.sequence.to_be_bytes());
+        header[4..8].copy_from_slice(&self.timestamp.to_be_bytes());
+        header[8..12].copy_from_slice(&self.ssrc.to_be_bytes());
+
+        let nonce = self.nonce.to_be_bytes();
+        let ciphertext = cipher.encrypt(
+            nonce,
+            Payload {
+                msg: opus,
+                aad: &header,
+            },
+        )?;
+
+        let mut packet = Vec::with_capacity(header.len() + ciphertext.len() + nonce.len());
+        packet.extend_from_slice(&header);
+        packet.extend_from_slice(&ciphertext);
+        packet.extend_from_slice(&nonce);
+        self.socket.send(&packet).await?;
+
+        self.sequence = self.sequence.wrapping_add(1);
+        self.timestamp = self.timestamp.wrapping_add(Self::FRAME_SAMPLES);
+        self.nonce = self.nonce.wrapping_add(1);
+
+        Ok(())
+    }
+
+    /// Waits for the next voice packet and decrypts it, skipping RTCP and other non-voice packets.
+    pub async fn recv_opus(&self) -> Result<VoicePacket> {
+        let cipher = self
+            .cipher
+            .as_ref()
+            .ok_or(VoiceError::MissingSessionDescription)?;
+
+        let mut buffer = [0; MAX_PACKET_LENGTH];
+        loop {
+            let length = self.socket.recv(&mut buffer).await?;
+            let packet = &buffer[..length];
+            if packet.len() < RTP_HEADER_LENGTH
+                || packet[0] >> 6 != 2
+                || packet[1] & 0x7f != RTP_PAYLOAD_TYPE
+            {
+                continue;
+            }
+
+            return decrypt_packet(cipher, packet);
+        }
+    }
+}
+
+/// With the `rtpsize` modes, the RTP header (including CSRCs and the extension header,
+/// but not the extension itself) is left unencrypted and authenticated as additional data.
+fn decrypt_packet(cipher: &Cipher, packet: &[u8]) -> Result<VoicePacket> {
+    let has_padding = packet[0] & 0x20 != 0;
+    let has_extension = packet[0] & 0x10 != 0;
+    let csrc_count = usize::from(packet[0] & 0x0f);
+
+    let mut header_length = RTP_HEADER_LENGTH + csrc_count * 4;
+    let mut extension_length = 0;
+    if has_extension {
+        header_length += 4;
+        let length = packet
+            .get(header_length - 2..header_length)
+            .ok_or(VoiceError::InvalidPacket)?;
+        extension_length = usize::from(u16::from_be_bytes([length[0], length[1]])) * 4;
+    }
+    if packet.len() < header_length + TAG_LENGTH + NONCE_LENGTH {
+        return Err(VoiceError::InvalidPacket);
+    }
+
+    let (rest, nonce) = packet.split_at(packet.len() - NONCE_LENGTH);
+    let (header, ciphertext) = rest.split_at(header_length);
+    let plaintext = cipher.decrypt(
+        nonce.try_into().expect("Split at the nonce length"),
+        Payload {
+            msg: ciphertext,
+            aad: header,
+        },
+    )?;
+
+    let mut opus = plaintext
+        .get(extension_length..)
+        .ok_or(VoiceError::InvalidPacket)?;
+    if has_padding {
+        let padding = usize::from(*opus.last().ok_or(VoiceError::InvalidPacket)?);
+        opus = opus
+            .get(
+                ..opus
+                    .len()
+                    .checked_sub(padding)
+                    .ok_or(VoiceError::InvalidPacket)?,
+            )
+            .ok_or(VoiceError::InvalidPacket)?;
+    }
+
+    Ok(VoicePacket {
+        ssrc: u32::from_be_bytes([packet[8], packet[9], packet[10], packet[11]]),
+        sequence: u16::from_be_bytes([packet[2], packet[3]]),
+        timestamp: u32::from_be_bytes([packet[4], packet[5], packet[6], packet[7]]),
+        opus: opus.to_vec(),
+    })
+}
+
+#[cfg(test)]
+async fn connected_pair(mode: EncryptionMode) -> (VoiceUdp, VoiceUdp) {
+    let first = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+    let second = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+    first.connect(second.local_addr().unwrap()).await.unwrap();
+    second.connect(first.local_addr().unwrap()).await.unwrap();
+
+    let session_description = SessionDescription {
+        mode,
+        secret_key: vec![7; 32],
+    };
+    let mut first = VoiceUdp::from_socket(first, 1);
+    let mut second = VoiceUdp::from_socket(second, 2);
+    first.set_session_description(&session_description).unwrap();
+    second
+        .set_session_description(&session_description)
+        .unwrap();
+
+    (first, second)
+}
+
+#[tokio::test]
+async fn test_ip_discovery() {
+    let server = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+    let ready = Ready {
+        ssrc: 1234,
+        ip: String::from("127.0.0.1"),
+        port: server.local_addr().unwrap().port(),
+        modes: vec![EncryptionMode::AeadAes256GcmRtpsize],
+    };
+    let udp = VoiceUdp::connect(&ready).await.unwrap();
+
+    let server_task = tokio::spawn(async move {
+        let mut request = [0; MAX_PACKET_LENGTH];
+        let (length, address) = server.recv_from(&mut request).await.unwrap();
+        assert_eq!(74, length);
+        assert_eq!([0, 1, 0, 70], request[..4]);
+        assert_eq!(1234u32.to_be_bytes(), request[4..8]);
+
+        let mut response = [0; 74];
+        response[..4].copy_from_slice(&[0, 2, 0, 70]);
+        response[4..8].copy_from_slice(&request[4..8]);
+        response[8..17].copy_from_slice(b"127.0.0.1");
+        response[72..].copy_from_slice(&address.port().to_be_bytes());
+        server.send_to(&response, address).await.unwrap();
+        address.port()
+    });
+
+    let (address, port) = udp.discover_ip().await.unwrap();
+    assert_eq!("127.0.0.1", address);
+    assert_eq!(server_task.await.unwrap(), port);
+}
+
+#[tokio::test]
+async fn test_send_and_recv_opus() {
+    for mode in [
+        EncryptionMode::AeadAes256GcmRtpsize,
+        EncryptionMode::AeadXchacha20Poly1305Rtpsize,
+    ] {
+        let (mut sender, receiver) = connected_pair(mode).await;
+        sender.sequence = u16::MAX;
+        sender.timestamp = u32::MAX - 100;
+
+        sender.send_opus(&[1, 2, 3, 4]).await.unwrap();
+        sender.send_opus(&VoiceUdp::SILENCE_FRAME).await.unwrap();
+
+        let packet = receiver.recv_opus().await.unwrap();
+        assert_eq!(1, packet.ssrc);
+        assert_eq!(u16::MAX, packet.sequence);
+        assert_eq!(u32::MAX - 100, packet.timestamp);
+        assert_eq!(vec![1, 2, 3, 4], packet.opus);
+
+        let packet = receiver.recv_opus().await.unwrap();
+        assert_eq!(0, packet.sequence);
+        assert_eq!(VoiceUdp::FRAME_SAMPLES - 101, packet.timestamp);
+        assert_eq!(VoiceUdp::SILENCE_FRAME.to_vec(), packet.opus);
+    }
+}
+
+#[tokio::test]
+async fn test_recv_opus_skips_extension_and_rejects_tampering() {
+    let (sender, receiver) = connected_pair(EncryptionMode::AeadAes256GcmRtpsize).await;
+    let cipher = sender.cipher.as_ref().unwrap();
+
+    // Header with the extension bit set, followed by a one word extension header.
+    let header = [0x90, 0x78, 0, 5, 0, 0, 0, 9, 0, 0, 0, 3, 0xbe, 0xde, 0, 1];
+    let nonce = 42u32.to_be_bytes();
+    let ciphertext = cipher
+        .encrypt(
+            nonce,
+            Payload {
+                msg: &[0xaa, 0xbb, 0xcc, 0xdd, 5, 6, 7],
+                aad: &header,
+            },
+        )
+        .unwrap();
+    let mut packet = [&header[..], &ciphertext, &nonce].concat();
+
+    // RTCP packets are ignored.
+    sender.socket.send(&[0x81, 0xc9, 0, 1]).await.unwrap();
+    sender.socket.send(&packet).await.unwrap();
+    let received = receiver.recv_opus().await.unwrap();
+    assert_eq!(3, received.ssrc);
+    assert_eq!(5, received.sequence);
+    assert_eq!(9, received.timestamp);
+    assert_eq!(vec![5, 6, 7], received.opus);
+
+    packet[3] = 6;
+    sender.socket.send(&packet).await.unwrap();
+    assert!(matches!(
+        receiver.recv_opus().await,
+        Err(VoiceError::EncryptionError)
+    ));
+}